@@ -0,0 +1,56 @@
+use r2r::{QosProfile, diagnostic_msgs::srv::AddDiagnostics};
+use rutile_r2r::future::*;
+
+/// Rejects the requests with an empty namespace, on the service and on the client side.
+struct ValidationLayer;
+
+impl Layer for ValidationLayer {
+    fn on_request(
+        &self,
+        _context: &LayerContext,
+        request: &mut dyn LayerMessage,
+    ) -> std::result::Result<(), String> {
+        match request.downcast_ref::<AddDiagnostics::Request>() {
+            Some(request) if request.load_namespace.is_empty() => {
+                Err("empty namespace".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+async fn service_callback(request: AddDiagnostics::Request) -> AddDiagnostics::Response {
+    AddDiagnostics::Response {
+        success: true,
+        message: format!("loaded {}", request.load_namespace),
+    }
+}
+
+async fn timer_callback(client: Client<AddDiagnostics::Service>) {
+    for load_namespace in ["/robot", ""] {
+        let request = AddDiagnostics::Request {
+            load_namespace: load_namespace.to_string(),
+        };
+        println!("{:?}", client.call(request).await);
+    }
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("layer", "")?;
+    //
+    // the layers apply to the services and clients created afterwards
+    let logger = node.logger();
+    node.add_layer(LoggingLayer::new(&logger));
+    node.add_layer(ValidationLayer);
+    //
+    node.create_service_0::<AddDiagnostics::Service, _, _>(
+        "add_diagnostics",
+        QosProfile::default(),
+        service_callback,
+    )?;
+    let client = node.create_client("add_diagnostics", QosProfile::default())?;
+    node.create_wall_timer_1(std::time::Duration::from_secs(1), timer_callback, client)?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use std::time::{Duration, Instant};
+
+use r2r::QosProfile;
+use r2r::sensor_msgs::msg::Image;
+use rutile_r2r::future::*;
+
+const COUNT: usize = 1000;
+
+fn report(name: &str, elapsed: Duration, size: usize) {
+    let rate = COUNT as f64 / elapsed.as_secs_f64();
+    println!(
+        "{name}: {:.0} msg/s, {:.1} MB/s",
+        rate,
+        rate * size as f64 / 1e6
+    );
+}
+
+fn main() -> Result<()> {
+    let node = Node::create("loaned_benchmark", "")?;
+    let size = node.get_parameter_with_default("size", 640 * 480 * 3)?;
+    let publisher: Publisher<Image> = node.create_publisher("image", QosProfile::default())?;
+    //
+    let image = Image {
+        height: 1,
+        width: size as u32,
+        encoding: "mono8".to_string(),
+        step: size as u32,
+        data: vec![0; size as usize],
+        ..Default::default()
+    };
+    //
+    let start = Instant::now();
+    for _ in 0..COUNT {
+        publisher.publish(&image);
+    }
+    report("publish", start.elapsed(), size as usize);
+    //
+    let start = Instant::now();
+    let mut loaned = true;
+    for _ in 0..COUNT {
+        let mut msg = publisher.borrow_loaned()?;
+        loaned &= msg.is_loaned();
+        msg.copy_from(&image);
+        msg.publish();
+    }
+    report(
+        if loaned {
+            "loaned"
+        } else {
+            "native (no loan support)"
+        },
+        start.elapsed(),
+        size as usize,
+    );
+    //
+    Ok(())
+}
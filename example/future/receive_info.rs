@@ -0,0 +1,19 @@
+use r2r::QosProfile;
+use rutile_r2r::future::*;
+
+async fn topic_callback(message: r2r::std_msgs::msg::String, info: ReceiveInfo) {
+    println!(
+        "[{}] taken at {:?}: '{}'",
+        info.take_index, info.taken_at, message.data
+    );
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("receive_info", "")?;
+    //
+    node.create_subscription_with_info_0("topic", QosProfile::default(), topic_callback)?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    //
+    Ok(())
+}
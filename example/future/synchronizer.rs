@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use r2r::QosProfile;
+use r2r::sensor_msgs::msg::{CameraInfo, Image};
+use rutile_r2r::future::*;
+
+async fn camera_callback((image, info): (Image, CameraInfo)) {
+    println!(
+        "image {}x{} with camera info {}x{} at {:?}",
+        image.width, image.height, info.width, info.height, image.header.stamp
+    );
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("synchronizer", "")?;
+    //
+    node.create_synchronizer_2(
+        ["camera/image", "camera/camera_info"],
+        QosProfile::default(),
+        SyncPolicy::SlopTime {
+            queue_size: 10,
+            slop: Duration::from_millis(20),
+        },
+        camera_callback,
+    )?;
+    //
+    node.spin(Duration::from_millis(10));
+    //
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use r2r::QosProfile;
+use rutile_r2r::future::*;
+
+#[derive(Default)]
+pub struct Data {
+    pub count: isize,
+    pub publisher: Publisher<r2r::std_msgs::msg::String>,
+}
+
+async fn timer_callback(data_mutex: FMutex<Data>) {
+    let mut data = data_mutex.lock().await;
+    //
+    let message = r2r::std_msgs::msg::String {
+        data: format!("Hello, world! {}", data.count),
+    };
+    data.count += 1;
+    //
+    data.publisher.publish(&message);
+}
+
+async fn topic_callback(message: r2r::std_msgs::msg::String) {
+    println!("I heard: '{:?}'", message);
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("throttle", "")?;
+    //
+    // the timer runs at 20Hz, at most 2 messages per second are published
+    let data = Data {
+        count: 0,
+        publisher: node.create_publisher_with_options(
+            "topic",
+            QosProfile::default(),
+            PublisherOptions::new().throttle(2.0, ThrottlePolicy::KeepLatest),
+        )?,
+    };
+    //
+    let data_mutex = FMutex::create(data);
+    //
+    node.create_wall_timer(
+        std::time::Duration::from_millis(50),
+        timer_callback,
+        data_mutex,
+    )?;
+    //
+    // at most 1 message per second is delivered to the callback
+    node.create_subscription_with_options_0(
+        "topic",
+        QosProfile::default(),
+        SubscriptionOptions::new().throttle(1.0, ThrottlePolicy::Drop),
+        topic_callback,
+    )?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    //
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use std::sync::{Arc, Mutex};
+
+use r2r::test_msgs::action::Fibonacci;
+use rutile_r2r::mono::*;
+
+#[derive(Clone)]
+pub struct Data {
+    pub order: Arc<Mutex<i32>>,
+    pub client: ActionClient<Fibonacci::Action>,
+}
+
+fn timer_callback(data: Data) {
+    let mut order = data.order.lock().unwrap_or_else(|e| e.into_inner());
+    let goal = Fibonacci::Goal { order: *order };
+    *order = (*order + 1) % 10;
+    drop(order);
+
+    println!("goal: {:?}", goal);
+    if let Err(e) = data.client.send_goal(
+        goal,
+        |feedback| println!("feedback: {:?}", feedback.sequence),
+        |result| match result {
+            Ok((status, result)) => println!("result: {:?} {:?}", status, result.sequence),
+            Err(e) => eprintln!("error: {:?}", e),
+        },
+    ) {
+        eprintln!("error: {:?}", e);
+    }
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("action_client", "")?;
+    let data = Data {
+        order: Arc::new(Mutex::new(5)),
+        client: node.create_action_client("fibonacci")?,
+    };
+
+    // The first goal is sent blocking, spinning the node until its result.
+    data.client
+        .wait_for_server(std::time::Duration::from_secs(5))?;
+    let (status, result) = data
+        .client
+        .send_goal_blocking(Fibonacci::Goal { order: 3 }, |_feedback| {})?;
+    println!("first result: {:?} {:?}", status, result.sequence);
+
+    node.create_wall_timer_1(std::time::Duration::from_secs(5), timer_callback, data)?;
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
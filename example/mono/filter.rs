@@ -0,0 +1,21 @@
+use r2r::QosProfile;
+use r2r::geometry_msgs::msg::PoseStamped;
+use rutile_r2r::mono::*;
+
+fn pose_callback(message: PoseStamped) {
+    println!("base_link pose: {:?}", message.pose.position);
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("filter", "")?;
+    // only the poses in "base_link" reach the callback
+    node.create_subscription_with_options_0(
+        "pose",
+        QosProfile::default(),
+        SubscriptionOptions::new()
+            .filter(|message: &PoseStamped| message.header.frame_id == "base_link"),
+        pose_callback,
+    )?;
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
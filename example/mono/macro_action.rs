@@ -0,0 +1,51 @@
+use r2r::{QosProfile, std_msgs::msg::String as StringMsg, test_msgs::action::Fibonacci};
+use rutile_r2r::mono::*;
+
+fn fibonacci(publisher: Publisher<StringMsg>, goal: ActionGoal<Fibonacci::Action>) {
+    let mut sequence = vec![0, 1];
+    while sequence.len() <= goal.goal().order as usize {
+        sequence.push(sequence[sequence.len() - 1] + sequence[sequence.len() - 2]);
+    }
+    let message = StringMsg {
+        data: format!("goal {}: {:?}", goal.goal_id(), sequence),
+    };
+    publisher.publish(&message);
+    if let Err(e) = goal.complete(GoalOutcome::Succeeded(Fibonacci::Result { sequence })) {
+        eprintln!("complete error: {}", e);
+    }
+}
+
+fn send_goal(client: ActionClient<Fibonacci::Action>) {
+    if let Err(e) = client.send_goal(
+        Fibonacci::Goal { order: 8 },
+        |_feedback| {},
+        |result| match result {
+            Ok((status, result)) => println!("{:?}: {:?}", status, result.sequence),
+            Err(e) => eprintln!("error: {}", e),
+        },
+    ) {
+        eprintln!("error: {}", e);
+    }
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("macro_action_mono", "")?;
+
+    let publisher =
+        rutile_r2r::create_publisher!(node, StringMsg, "fibonacci_log", QosProfile::default())?;
+    rutile_r2r::create_action_server!(
+        node,
+        Fibonacci::Action,
+        "fibonacci",
+        |goal| goal.order >= 0 && goal.order < 50,
+        |_goal| true,
+        fibonacci,
+        publisher,
+    )?;
+
+    let client = rutile_r2r::create_action_client!(node, Fibonacci::Action, "fibonacci")?;
+    rutile_r2r::create_wall_timer!(node, std::time::Duration::from_secs(1), send_goal, client)?;
+
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use r2r::QosProfile;
+use rutile_r2r::mono::*;
+
+fn main() -> Result<()> {
+    let mut node = Node::create("untyped", "")?;
+    // the message type is only known at runtime
+    let topic_type = node.get_parameter_with_default("type", "std_msgs/msg/String".to_string())?;
+    let publisher = node.create_untyped_publisher("echo", &topic_type, QosProfile::default())?;
+    node.create_untyped_subscription(
+        "topic",
+        &topic_type,
+        QosProfile::default(),
+        move |message: serde_json::Value| {
+            println!("I heard: {}", message);
+            publisher.publish(message);
+        },
+    )?;
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
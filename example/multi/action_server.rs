@@ -0,0 +1,47 @@
+use r2r::test_msgs::action::Fibonacci;
+use rutile_r2r::multi::*;
+
+fn accept_goal(goal: &Fibonacci::Goal) -> bool {
+    goal.order >= 0 && goal.order < 50
+}
+
+fn fibonacci(period: std::time::Duration, goal: ActionGoal<Fibonacci::Action>) {
+    let order = goal.goal().order as usize;
+    let mut sequence = vec![0, 1];
+    let outcome = loop {
+        if sequence.len() > order {
+            break GoalOutcome::Succeeded(Fibonacci::Result { sequence });
+        }
+        if goal.is_cancel_requested() {
+            break GoalOutcome::Canceled(Fibonacci::Result { sequence });
+        }
+        let next = sequence[sequence.len() - 1] + sequence[sequence.len() - 2];
+        sequence.push(next);
+        let feedback = Fibonacci::Feedback {
+            sequence: sequence.clone(),
+        };
+        if let Err(e) = goal.publish_feedback(feedback) {
+            eprintln!("feedback error: {}", e);
+            break GoalOutcome::Aborted(Fibonacci::Result { sequence });
+        }
+        std::thread::sleep(period);
+    };
+    if let Err(e) = goal.complete(outcome) {
+        eprintln!("complete error: {}", e);
+    }
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("action_server", "")?;
+    //
+    node.create_action_server_1::<Fibonacci::Action, _, _, _, _>(
+        "fibonacci",
+        accept_goal,
+        |_goal| true,
+        fibonacci,
+        std::time::Duration::from_millis(500),
+    )?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::time::{Duration, Instant};
+
+use r2r::{QosProfile, diagnostic_msgs::srv::AddDiagnostics};
+use rutile_r2r::mono::NodeMono;
+use rutile_r2r::multi::*;
+
+const COUNT: u32 = 1000;
+
+/// CPU time of the calling thread, from `/proc` (Linux only).
+fn thread_cpu_time() -> Duration {
+    let stat = std::fs::read_to_string("/proc/thread-self/stat").unwrap_or_default();
+    let fields: Vec<&str> = stat
+        .rsplit(')')
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .collect();
+    // utime and stime, in clock ticks of 10ms
+    let ticks: u64 = fields
+        .get(11..13)
+        .map(|times| {
+            times
+                .iter()
+                .filter_map(|time| time.parse::<u64>().ok())
+                .sum()
+        })
+        .unwrap_or_default();
+    Duration::from_millis(ticks * 10)
+}
+
+fn benchmark<F>(name: &str, mut call: F)
+where
+    F: FnMut(AddDiagnostics::Request) -> Result<AddDiagnostics::Response>,
+{
+    let start = Instant::now();
+    let cpu_start = thread_cpu_time();
+    for i in 0..COUNT {
+        let request = AddDiagnostics::Request {
+            load_namespace: format!("/robot/{i}"),
+        };
+        if let Err(e) = call(request) {
+            eprintln!("{name}: {e}");
+            return;
+        }
+    }
+    let elapsed = start.elapsed();
+    let cpu = thread_cpu_time() - cpu_start;
+    println!(
+        "{name}: {:?} per call, {:.0}% CPU while calling",
+        elapsed / COUNT,
+        100.0 * cpu.as_secs_f64() / elapsed.as_secs_f64()
+    );
+}
+
+fn run(client: Client<AddDiagnostics::Service>) -> Result<()> {
+    client.wait_for_service(Duration::from_secs(10))?;
+    benchmark("multi call_blocking", |request| {
+        client.call_blocking(request)
+    });
+    //
+    // the mono node spins itself while waiting
+    let node = rutile_r2r::mono::Node::create("client_benchmark_mono", "")?;
+    let client: rutile_r2r::mono::Client<AddDiagnostics::Service> =
+        node.create_client("add_diagnostics", QosProfile::default())?;
+    client.wait_for_service(Duration::from_secs(10))?;
+    benchmark("mono call_blocking", |request| {
+        client.call_blocking(request)
+    });
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("client_benchmark", "")?;
+    node.create_service_0::<AddDiagnostics::Service, _>(
+        "add_diagnostics",
+        QosProfile::default(),
+        |request| AddDiagnostics::Response {
+            success: true,
+            message: request.load_namespace,
+        },
+    )?;
+    let client = node.create_client("add_diagnostics", QosProfile::default())?;
+    //
+    // the multi client blocks a thread while the node spins on the main thread
+    std::thread::spawn(move || {
+        if let Err(e) = run(client) {
+            eprintln!("error: {e}");
+        }
+        std::process::exit(0);
+    });
+    //
+    node.spin(Duration::from_millis(1));
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use r2r::QosProfile;
+use rutile_r2r::multi::*;
+
+fn slow_callback(message: r2r::std_msgs::msg::String) {
+    // the topic is published faster than this callback can keep up
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    println!("Latest: '{}'", message.data);
+}
+
+fn concurrent_callback(message: r2r::std_msgs::msg::String) {
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    println!("Concurrent: '{}'", message.data);
+}
+
+fn stats_callback(stats: SubscriptionStats) {
+    println!(
+        "received: {}, dropped: {}",
+        stats.received(),
+        stats.dropped()
+    );
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("delivery", "")?;
+    //
+    // stale messages are skipped while the callback is busy
+    let stats = SubscriptionStats::new();
+    node.create_subscription_with_options_0(
+        "topic",
+        QosProfile::default(),
+        SubscriptionOptions::new()
+            .policy(DeliveryPolicy::LatestOnly)
+            .stats(&stats),
+        slow_callback,
+    )?;
+    //
+    // up to 4 messages are processed at the same time on the thread pool
+    node.create_subscription_with_options_0(
+        "topic",
+        QosProfile::default(),
+        SubscriptionOptions::new().policy(DeliveryPolicy::Concurrent(4)),
+        concurrent_callback,
+    )?;
+    //
+    node.create_wall_timer(std::time::Duration::from_secs(1), stats_callback, stats)?;
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use r2r::QosProfile;
+use r2r::sensor_msgs::msg::Image;
+use rutile_r2r::multi::*;
+
+fn timer_callback(publisher: Publisher<Image>) {
+    let image = Image {
+        height: 480,
+        width: 640,
+        encoding: "rgb8".to_string(),
+        step: 640 * 3,
+        data: vec![0; 640 * 480 * 3],
+        ..Default::default()
+    };
+    // shared with the in-process subscription below, and published to DDS for the other subscribers
+    publisher.publish(&image);
+}
+
+fn image_callback(image: std::sync::Arc<Image>) {
+    println!("Received {}x{} image", image.width, image.height);
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("intra_process", "")?;
+    //
+    let publisher: Publisher<Image> = node.create_publisher_with_options(
+        "image",
+        QosProfile::default(),
+        PublisherOptions::new().intra_process(),
+    )?;
+    node.create_subscription_shared_0(
+        "image",
+        QosProfile::default(),
+        SubscriptionOptions::new(),
+        image_callback,
+    )?;
+    //
+    node.create_wall_timer(
+        std::time::Duration::from_millis(100),
+        timer_callback,
+        publisher,
+    )?;
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use r2r::QosProfile;
+use r2r::nav_msgs::msg::Odometry;
+use rutile_r2r::multi::*;
+
+fn timer_callback(odometry: Latest<Odometry>) {
+    match (odometry.get(), odometry.age()) {
+        (Some(odometry), Some(age)) => println!(
+            "position: {:?} ({:?} ago)",
+            odometry.pose.pose.position, age
+        ),
+        _ => println!("no odometry yet"),
+    }
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("latest", "")?;
+    let odometry = node.create_latest::<Odometry>("odom", QosProfile::default())?;
+    node.create_wall_timer(std::time::Duration::from_secs(1), timer_callback, odometry)?;
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use r2r::QosProfile;
+use rutile_r2r::multi::*;
+
+fn main() -> Result<()> {
+    let mut node = Node::create("raw_relay", "")?;
+    // messages are relayed without being deserialized
+    let topic_type = node.get_parameter_with_default("type", "std_msgs/msg/String".to_string())?;
+    let publisher = node.create_untyped_publisher("output", &topic_type, QosProfile::default())?;
+    node.create_raw_subscription(
+        "input",
+        &topic_type,
+        QosProfile::default(),
+        move |message: RawMessage| {
+            println!("{}: {} bytes", message.type_name, message.data.len());
+            publisher.publish_raw(&message.data);
+        },
+    )?;
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use r2r::{QosProfile, diagnostic_msgs::srv::AddDiagnostics};
+use rutile_r2r::multi::*;
+
+fn slow_service(request: AddDiagnostics::Request) -> AddDiagnostics::Response {
+    // a slow request does not delay the others
+    std::thread::sleep(std::time::Duration::from_secs(2));
+    println!("loaded {}", request.load_namespace);
+    AddDiagnostics::Response {
+        success: true,
+        message: String::new(),
+    }
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("service_concurrent", "")?;
+    //
+    // up to 4 requests are handled at the same time on the thread pool
+    node.create_service_concurrent_0::<AddDiagnostics::Service, _>(
+        "add_diagnostics",
+        QosProfile::default(),
+        4,
+        slow_service,
+    )?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
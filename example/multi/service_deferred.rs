@@ -0,0 +1,33 @@
+use r2r::{QosProfile, diagnostic_msgs::srv::AddDiagnostics};
+use rutile_r2r::multi::*;
+
+// forwards the request to another service, answering once it has answered
+fn forward(
+    client: Client<AddDiagnostics::Service>,
+    request: AddDiagnostics::Request,
+    responder: Responder<AddDiagnostics::Service>,
+) {
+    let result = client.call(request, move |result| match result {
+        Ok(response) => responder.respond(response),
+        Err(e) => responder.fail(e),
+    });
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+    }
+}
+
+fn main() -> Result<()> {
+    let mut node = Node::create("service_deferred", "")?;
+    //
+    let client: Client<AddDiagnostics::Service> =
+        node.create_client("add_diagnostics", QosProfile::default())?;
+    node.create_service_deferred_1::<AddDiagnostics::Service, _, _>(
+        "add_diagnostics_proxy",
+        QosProfile::default(),
+        forward,
+        client,
+    )?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use futures::StreamExt;
+use r2r::test_msgs::action::Fibonacci;
+use rutile_r2r::tokio::*;
+
+async fn run(client: ActionClient<Fibonacci::Action>) -> Result<()> {
+    client
+        .wait_for_server(std::time::Duration::from_secs(10))
+        .await?;
+    //
+    let goal = client.send_goal(Fibonacci::Goal { order: 10 }).await?;
+    println!("goal {} accepted", goal.goal_id());
+    let mut feedback = goal.feedback();
+    tokio::spawn(async move {
+        while let Some(feedback) = feedback.next().await {
+            println!("feedback: {:?}", feedback.sequence);
+        }
+    });
+    let (status, result) = goal
+        .result_timeout(std::time::Duration::from_secs(30))
+        .await?;
+    println!("{:?}: {:?}", status, result.sequence);
+    //
+    // a long goal, canceled after a while
+    let goal = client.send_goal(Fibonacci::Goal { order: 40 }).await?;
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    goal.cancel().await?;
+    let (status, result) = goal.result().await?;
+    println!("{:?}: {:?}", status, result.sequence);
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut node = Node::create("action_client", "")?;
+    //
+    let client = node.create_action_client::<Fibonacci::Action>("fibonacci")?;
+    tokio::spawn(async move {
+        if let Err(e) = run(client).await {
+            eprintln!("error: {}", e);
+        }
+    });
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
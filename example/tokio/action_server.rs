@@ -0,0 +1,46 @@
+use r2r::test_msgs::action::Fibonacci;
+use rutile_r2r::tokio::*;
+
+fn accept_goal(goal: &Fibonacci::Goal) -> bool {
+    goal.order >= 0 && goal.order < 50
+}
+
+async fn fibonacci(
+    period: std::time::Duration,
+    goal: ActionGoal<Fibonacci::Action>,
+) -> GoalOutcome<Fibonacci::Result> {
+    let order = goal.goal().order as usize;
+    let mut sequence = vec![0, 1];
+    while sequence.len() <= order {
+        if goal.is_cancel_requested() {
+            return GoalOutcome::Canceled(Fibonacci::Result { sequence });
+        }
+        let next = sequence[sequence.len() - 1] + sequence[sequence.len() - 2];
+        sequence.push(next);
+        let feedback = Fibonacci::Feedback {
+            sequence: sequence.clone(),
+        };
+        if let Err(e) = goal.publish_feedback(feedback) {
+            eprintln!("feedback error: {}", e);
+            return GoalOutcome::Aborted(Fibonacci::Result { sequence });
+        }
+        tokio::time::sleep(period).await;
+    }
+    GoalOutcome::Succeeded(Fibonacci::Result { sequence })
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut node = Node::create("action_server", "")?;
+    //
+    node.create_action_server_1::<Fibonacci::Action, _, _, _, _, _>(
+        "fibonacci",
+        accept_goal,
+        |_goal| true,
+        fibonacci,
+        std::time::Duration::from_millis(500),
+    )?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
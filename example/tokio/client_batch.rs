@@ -0,0 +1,62 @@
+use r2r::{QosProfile, diagnostic_msgs::srv::AddDiagnostics};
+use rutile_r2r::tokio::*;
+
+pub struct Data {
+    pub client: Client<AddDiagnostics::Service>,
+    pub replicas: Vec<Client<AddDiagnostics::Service>>,
+}
+
+async fn timer_callback(data: TMutex<Data>) {
+    let (client, replicas) = {
+        let data = data.lock().await;
+        (data.client.clone(), data.replicas.clone())
+    };
+    //
+    // 200 waypoints, at most 10 requests in flight, the results in order
+    let requests = (0..200)
+        .map(|i| AddDiagnostics::Request {
+            load_namespace: format!("/waypoints/{i}"),
+        })
+        .collect();
+    let (loaded, count) = {
+        let results = client.call_many(requests, 10).await;
+        let loaded = results.iter().filter(|result| result.is_ok()).count();
+        (loaded, results.len())
+    };
+    println!("{loaded}/{count} waypoints loaded");
+    //
+    // the first replica to answer
+    let request = AddDiagnostics::Request {
+        load_namespace: "/robot".to_string(),
+    };
+    println!(
+        "first: {:?}",
+        Client::call_first(&replicas, request.clone()).await
+    );
+    //
+    // every replica
+    for result in Client::call_all(&replicas, request).await {
+        println!("all: {:?}", result);
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut node = Node::create("client_batch", "")?;
+    //
+    let data = Data {
+        client: node.create_client("add_diagnostics", QosProfile::default())?,
+        replicas: vec![
+            node.create_client("add_diagnostics_1", QosProfile::default())?,
+            node.create_client("add_diagnostics_2", QosProfile::default())?,
+        ],
+    };
+    node.create_wall_timer_1(
+        std::time::Duration::from_secs(5),
+        timer_callback,
+        TMutex::create(data),
+    )?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use r2r::{QosProfile, diagnostic_msgs::srv::AddDiagnostics};
+use rutile_r2r::tokio::*;
+
+async fn load(client: Client<AddDiagnostics::Service>) {
+    // the node spins meanwhile, the wait only blocks this task
+    if let Err(e) = client
+        .wait_for_service(std::time::Duration::from_secs(10))
+        .await
+    {
+        eprintln!("error: {}", e);
+        return;
+    }
+    let request = AddDiagnostics::Request {
+        load_namespace: "/robot".to_string(),
+    };
+    println!("{:?}", client.call(request).await);
+}
+
+async fn timer_callback(client: Client<AddDiagnostics::Service>) {
+    // skip the call rather than waiting for its timeout
+    if !client.is_service_ready() {
+        println!("service not ready, skipped");
+        return;
+    }
+    let request = AddDiagnostics::Request {
+        load_namespace: "/robot/arm".to_string(),
+    };
+    println!("{:?}", client.call(request).await);
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut node = Node::create("client_wait", "")?;
+    //
+    let client: Client<AddDiagnostics::Service> =
+        node.create_client("add_diagnostics", QosProfile::default())?;
+    client.on_availability_change(|ready| println!("service available: {}", ready))?;
+    //
+    tokio::spawn(load(client.clone()));
+    node.create_wall_timer_1(std::time::Duration::from_secs(1), timer_callback, client)?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use r2r::{QosProfile, diagnostic_msgs::srv::AddDiagnostics};
+use rutile_r2r::tokio::*;
+
+async fn load(request: AddDiagnostics::Request) -> Result<AddDiagnostics::Response> {
+    if !request.load_namespace.starts_with('/') {
+        return Err(format!("invalid namespace '{}'", request.load_namespace).into());
+    }
+    println!("loading {}", request.load_namespace);
+    Ok(AddDiagnostics::Response {
+        success: true,
+        message: String::new(),
+    })
+}
+
+async fn load_with_prefix(
+    prefix: String,
+    request: AddDiagnostics::Request,
+) -> Result<AddDiagnostics::Response> {
+    load(AddDiagnostics::Request {
+        load_namespace: format!("{}{}", prefix, request.load_namespace),
+    })
+    .await
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut node = Node::create("service_try", "")?;
+    //
+    // errors are logged and answered with a failure response
+    node.create_service_try_0::<AddDiagnostics::Service, _, _, _>(
+        "add_diagnostics",
+        QosProfile::default(),
+        ServiceErrorPolicy::respond(|error| AddDiagnostics::Response {
+            success: false,
+            message: error,
+        }),
+        load,
+    )?;
+    //
+    // errors are logged and the request is left unanswered
+    rutile_r2r::create_service!(
+        try node,
+        AddDiagnostics::Service,
+        "add_diagnostics_prefixed",
+        QosProfile::default(),
+        ServiceErrorPolicy::Drop,
+        load_with_prefix,
+        "/robot".to_string(),
+    )?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
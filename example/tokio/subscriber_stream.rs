@@ -0,0 +1,29 @@
+use futures::StreamExt;
+use r2r::QosProfile;
+use rutile_r2r::tokio::*;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut node = Node::create("stream_subscriber", "")?;
+    //
+    let mut topic =
+        node.subscribe_stream::<r2r::std_msgs::msg::String>("topic", QosProfile::default())?;
+    // messages of "other_topic" are handled 5 by 5
+    let mut other = node
+        .subscribe_stream::<r2r::std_msgs::msg::String>("other_topic", QosProfile::default())?
+        .chunks(5);
+    //
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                Some(message) = topic.next() => println!("topic: '{}'", message.data),
+                Some(messages) = other.next() => println!("other_topic: {} messages", messages.len()),
+                else => break,
+            }
+        }
+    });
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    //
+    Ok(())
+}
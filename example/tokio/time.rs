@@ -0,0 +1,28 @@
+use r2r::QosProfile;
+use rutile_r2r::tokio::*;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut node = Node::create("time", "")?;
+    let publisher =
+        node.create_publisher::<r2r::std_msgs::msg::Header>("stamped", QosProfile::default())?;
+    //
+    let ros = node.now_with(ClockType::Ros);
+    let system = node.now_with(ClockType::System);
+    let steady = node.now_with(ClockType::Steady);
+    println!("ros: {:?}", ros);
+    println!("system: {:?}", system);
+    println!("steady: {:?}", steady);
+    //
+    let deadline = steady + std::time::Duration::from_millis(100);
+    tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+    let late = node.now_with(ClockType::Steady).duration_since(deadline);
+    println!("late by {:?}", late);
+    //
+    let header = node.now_with(ClockType::Ros).to_header("base_link");
+    println!("header: {:?}, time: {:?}", header, Time::from(&header));
+    publisher.publish(&header);
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
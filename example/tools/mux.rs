@@ -0,0 +1,27 @@
+use r2r::QosProfile;
+use r2r::std_msgs::msg::String as StringMsg;
+use rutile_r2r::multi::*;
+use rutile_r2r::tools::node_multi;
+
+// ros2 run ... tools_mux --ros-args -p inputs:=/a,/b -p output:=/c
+// ros2 topic pub --once /c/select std_msgs/msg/String "{data: /b}"
+fn main() -> Result<()> {
+    let mut node = Node::create("mux", "")?;
+    let inputs = node.get_parameter_with_default("inputs", "input_1,input_2".to_string())?;
+    let inputs = inputs.split(',').map(str::trim).collect::<Vec<_>>();
+    let output = node.get_parameter_with_default("output", "output".to_string())?;
+    //
+    let mux = node_multi::mux::<_, StringMsg>(&node, &inputs, &output, QosProfile::default())?;
+    node.create_subscription_0(
+        &format!("{output}/select"),
+        QosProfile::default(),
+        move |request: StringMsg| {
+            if !mux.select(&request.data) {
+                println!("'{}' is not an input of {:?}", request.data, mux.inputs());
+            }
+        },
+    )?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
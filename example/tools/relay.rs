@@ -0,0 +1,21 @@
+use r2r::QosProfile;
+use rutile_r2r::multi::*;
+
+// ros2 run ... tools_relay --ros-args -p from:=/a -p to:=/b -p type:=std_msgs/msg/String
+fn main() -> Result<()> {
+    let mut node = Node::create("relay", "")?;
+    let from = node.get_parameter_with_default("from", "input".to_string())?;
+    let to = node.get_parameter_with_default("to", "output".to_string())?;
+    let topic_type = node.get_parameter_with_default("type", "std_msgs/msg/String".to_string())?;
+    //
+    let publisher = node.create_untyped_publisher(&to, &topic_type, QosProfile::default())?;
+    node.create_raw_subscription(
+        &from,
+        &topic_type,
+        QosProfile::default(),
+        move |message: RawMessage| publisher.publish_raw(&message.data),
+    )?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
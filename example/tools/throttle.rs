@@ -0,0 +1,29 @@
+use r2r::QosProfile;
+use rutile_r2r::Throttle;
+use rutile_r2r::multi::*;
+
+// ros2 run ... tools_throttle --ros-args -p from:=/a -p to:=/b -p type:=std_msgs/msg/String -p max_hz:=1.0
+fn main() -> Result<()> {
+    let mut node = Node::create("throttle", "")?;
+    let from = node.get_parameter_with_default("from", "input".to_string())?;
+    let to = node.get_parameter_with_default("to", "output".to_string())?;
+    let topic_type = node.get_parameter_with_default("type", "std_msgs/msg/String".to_string())?;
+    let max_hz = node.get_parameter_with_default("max_hz", 1.0)?;
+    //
+    let publisher = node.create_untyped_publisher(&to, &topic_type, QosProfile::default())?;
+    let throttle = SMutex::create(Throttle::new(max_hz, ThrottlePolicy::Drop));
+    node.create_raw_subscription(
+        &from,
+        &topic_type,
+        QosProfile::default(),
+        move |message: RawMessage| {
+            let message = throttle.lock_or_log("throttle").offer(message);
+            if let Some(message) = message {
+                publisher.publish_raw(&message.data);
+            }
+        },
+    )?;
+    //
+    node.spin(std::time::Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,505 @@
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::future::{BoxFuture, Either, Shared};
+use futures::stream::BoxStream;
+use futures::{FutureExt, Stream, StreamExt, TryFutureExt};
+
+use crate::{MutexCreate, MutexLockErr, MutexLockOrLog, SMutex};
+
+/// How the execution of a goal ended, with its result.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GoalOutcome<Res> {
+    Succeeded(Res),
+    Aborted(Res),
+    /// To be returned once a cancel request has been accepted, see `ActionGoal::is_cancel_requested`.
+    Canceled(Res),
+}
+
+type Completion<Res> = SMutex<Option<futures::channel::oneshot::Sender<GoalOutcome<Res>>>>;
+type Completed<Res> = futures::channel::oneshot::Receiver<GoalOutcome<Res>>;
+
+/// Goal being executed by an action server, cheap to clone.
+pub struct ActionGoal<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    r2r_goal: SMutex<r2r::ActionServerGoal<A>>,
+    cancel: Arc<tokio::sync::watch::Sender<bool>>,
+    completion: Completion<A::Result>,
+}
+
+impl<A> Clone for ActionGoal<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    fn clone(&self) -> Self {
+        Self {
+            r2r_goal: self.r2r_goal.clone(),
+            cancel: self.cancel.clone(),
+            completion: self.completion.clone(),
+        }
+    }
+}
+
+impl<A> ActionGoal<A>
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+{
+    fn new(r2r_goal: r2r::ActionServerGoal<A>) -> (Self, Completed<A::Result>) {
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        let goal = Self {
+            r2r_goal: SMutex::create(r2r_goal),
+            cancel: Arc::new(tokio::sync::watch::Sender::new(false)),
+            completion: SMutex::create(Some(sender)),
+        };
+        (goal, receiver)
+    }
+
+    pub fn goal_id(&self) -> String {
+        self.r2r_goal.lock_or_log("goal").uuid.to_string()
+    }
+
+    pub fn goal(&self) -> A::Goal {
+        self.r2r_goal.lock_or_log("goal").goal.clone()
+    }
+
+    pub fn publish_feedback(&self, feedback: A::Feedback) -> crate::Result<()> {
+        self.r2r_goal.lock_err("goal")?.publish_feedback(feedback)?;
+        Ok(())
+    }
+
+    /// Whether a cancel request of the goal has been accepted.
+    pub fn is_cancel_requested(&self) -> bool {
+        *self.cancel.borrow()
+    }
+
+    /// Completes once a cancel request of the goal has been accepted.
+    pub fn cancel_requested(&self) -> impl Future<Output = ()> + Send + 'static {
+        let mut receiver = self.cancel.subscribe();
+        async move {
+            let _ = receiver.wait_for(|cancel| *cancel).await;
+        }
+    }
+
+    /// Ends the goal with `outcome`, the way the synchronous nodes finish their goals.
+    ///
+    /// The goal is aborted when all its clones are dropped before it is completed.
+    pub fn complete(&self, outcome: GoalOutcome<A::Result>) -> crate::Result<()> {
+        match self.completion.lock_err("completion")?.take() {
+            Some(sender) => sender
+                .send(outcome)
+                .map_err(|_| "action goal already finished".into()),
+            None => Err("action goal already completed".into()),
+        }
+    }
+}
+
+/// Gives `callback` the goal type of `A`, for `create_action_server!`.
+#[doc(hidden)]
+pub fn action_goal_callback<A, G>(callback: G) -> G
+where
+    A: r2r::WrappedActionTypeSupport,
+    G: Fn(&A::Goal) -> bool,
+{
+    callback
+}
+
+/// Serves the goal `requests` of an action server.
+///
+/// `spawn` runs each accepted goal, the goals are executed concurrently.
+/// The execution returned by `callback` may leave the outcome to `ActionGoal::complete`.
+pub(crate) fn serve<A, G, C, F, R, S, SR>(
+    requests: impl Stream<Item = r2r::ActionServerGoalRequest<A>>,
+    logger: String,
+    goal_callback: G,
+    cancel_callback: C,
+    callback: F,
+    mut spawn: S,
+) -> impl Future<Output = ()>
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+    A::Result: Send,
+    G: Fn(&A::Goal) -> bool,
+    C: Fn(&A::Goal) -> bool + Send + Sync + 'static,
+    F: Fn(ActionGoal<A>) -> R,
+    R: Future<Output = Option<GoalOutcome<A::Result>>> + Send + 'static,
+    S: FnMut(BoxFuture<'static, ()>) -> SR,
+    SR: Future<Output = ()>,
+{
+    let cancel_callback = Arc::new(cancel_callback);
+    requests.for_each_concurrent(None, move |request| {
+        let run = accept(request, &goal_callback, &logger).map(|(r2r_goal, cancel_requests)| {
+            let (goal, completed) = ActionGoal::new(r2r_goal.clone());
+            let cancel = goal.cancel.clone();
+            let run = run_goal(
+                r2r_goal,
+                cancel_requests,
+                callback(goal),
+                completed,
+                cancel,
+                cancel_callback.clone(),
+                logger.clone(),
+            );
+            spawn(run.boxed())
+        });
+        futures::future::OptionFuture::from(run).map(|_| ())
+    })
+}
+
+/// Serves the goal `requests` of an action server within the current task, see `serve`.
+pub(crate) fn serve_local<A, G, C, F, R>(
+    requests: impl Stream<Item = r2r::ActionServerGoalRequest<A>>,
+    logger: String,
+    goal_callback: G,
+    cancel_callback: C,
+    callback: F,
+) -> impl Future<Output = ()>
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+    G: Fn(&A::Goal) -> bool,
+    C: Fn(&A::Goal) -> bool + 'static,
+    F: Fn(ActionGoal<A>) -> R,
+    R: Future<Output = Option<GoalOutcome<A::Result>>>,
+{
+    let cancel_callback = Arc::new(cancel_callback);
+    requests.for_each_concurrent(None, move |request| {
+        let run = accept(request, &goal_callback, &logger).map(|(r2r_goal, cancel_requests)| {
+            let (goal, completed) = ActionGoal::new(r2r_goal.clone());
+            let cancel = goal.cancel.clone();
+            run_goal(
+                r2r_goal,
+                cancel_requests,
+                callback(goal),
+                completed,
+                cancel,
+                cancel_callback.clone(),
+                logger.clone(),
+            )
+        });
+        futures::future::OptionFuture::from(run).map(|_| ())
+    })
+}
+
+fn accept<A>(
+    request: r2r::ActionServerGoalRequest<A>,
+    goal_callback: &dyn Fn(&A::Goal) -> bool,
+    logger: &str,
+) -> Option<(
+    r2r::ActionServerGoal<A>,
+    impl Stream<Item = r2r::ActionServerCancelRequest> + Unpin + use<A>,
+)>
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+{
+    if goal_callback(&request.goal) {
+        match request.accept() {
+            Ok(accepted) => Some(accepted),
+            Err(e) => {
+                r2r::log_error!(logger, "action goal accept error: {}", e);
+                None
+            }
+        }
+    } else {
+        if let Err(e) = request.reject() {
+            r2r::log_error!(logger, "action goal reject error: {}", e);
+        }
+        None
+    }
+}
+
+async fn run_goal<A, C, R, K>(
+    r2r_goal: r2r::ActionServerGoal<A>,
+    cancel_requests: K,
+    execution: R,
+    completed: Completed<A::Result>,
+    cancel: Arc<tokio::sync::watch::Sender<bool>>,
+    cancel_callback: Arc<C>,
+    logger: String,
+) where
+    A: 'static + r2r::WrappedActionTypeSupport,
+    C: Fn(&A::Goal) -> bool,
+    R: Future<Output = Option<GoalOutcome<A::Result>>>,
+    K: Stream<Item = r2r::ActionServerCancelRequest> + Unpin,
+{
+    let goal = SMutex::create(r2r_goal.clone());
+    let cancels = cancel_requests.for_each(move |request| {
+        if cancel_callback(&goal.lock_or_log("goal").goal) {
+            cancel.send_replace(true);
+            request.accept();
+        } else {
+            request.reject();
+        }
+        futures::future::ready(())
+    });
+    let outcome = async {
+        let dropped = || {
+            r2r::log_error!(&logger, "action goal dropped before completion");
+            GoalOutcome::Aborted(A::Result::default())
+        };
+        match futures::future::select(pin!(execution), completed).await {
+            Either::Left((Some(outcome), _)) => outcome,
+            Either::Left((None, completed)) => completed.await.unwrap_or_else(|_| dropped()),
+            Either::Right((Ok(outcome), _)) => outcome,
+            Either::Right((Err(_), execution)) => execution.await.unwrap_or_else(dropped),
+        }
+    };
+    let outcome = match futures::future::select(pin!(outcome), cancels).await {
+        Either::Left((outcome, _)) => outcome,
+        Either::Right((_, outcome)) => outcome.await,
+    };
+    finish(r2r_goal, outcome, &logger);
+}
+
+fn finish<A>(mut r2r_goal: r2r::ActionServerGoal<A>, outcome: GoalOutcome<A::Result>, logger: &str)
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+{
+    let result = match outcome {
+        GoalOutcome::Succeeded(result) => r2r_goal.succeed(result),
+        GoalOutcome::Aborted(result) => r2r_goal.abort(result),
+        GoalOutcome::Canceled(result) => r2r_goal.cancel(result),
+    };
+    if let Err(e) = result {
+        r2r::log_error!(logger, "action result error: {}", e);
+    }
+}
+
+/// Client of an action server, cheap to clone.
+pub enum ActionClient<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    Empty,
+    Defined {
+        r2r_client: SMutex<r2r::ActionClient<A>>,
+        action_name: String,
+    },
+}
+
+impl<A> Default for ActionClient<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    fn default() -> Self {
+        Self::Empty
+    }
+}
+
+impl<A> Clone for ActionClient<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Empty => Self::Empty,
+            Self::Defined {
+                r2r_client,
+                action_name,
+            } => Self::Defined {
+                r2r_client: r2r_client.clone(),
+                action_name: action_name.clone(),
+            },
+        }
+    }
+}
+
+impl<A> ActionClient<A>
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+    A::Result: Send,
+    A::Feedback: Send,
+{
+    pub(crate) fn new(r2r_client: r2r::ActionClient<A>, action_name: &str) -> Self {
+        Self::Defined {
+            r2r_client: SMutex::create(r2r_client),
+            action_name: action_name.to_string(),
+        }
+    }
+
+    /// Waits until the action server is available, at most `timeout`.
+    pub async fn wait_for_server(&self, timeout: Duration) -> crate::Result<()> {
+        let Self::Defined {
+            r2r_client,
+            action_name,
+        } = self
+        else {
+            return Err("action client not initialized".into());
+        };
+        let is_available = r2r::Node::is_available(&*r2r_client.lock_err("action_client")?)?;
+        match crate::availability::timeout(is_available, timeout).await {
+            Some(result) => Ok(result?),
+            None => Err(crate::availability::not_available(action_name, timeout)),
+        }
+    }
+
+    /// Sends `goal` once the action server is available, fails when the goal is rejected.
+    pub async fn send_goal(&self, goal: A::Goal) -> crate::Result<GoalHandle<A>> {
+        let Self::Defined { r2r_client, .. } = self else {
+            return Err("action client not initialized".into());
+        };
+        let is_available = r2r::Node::is_available(&*r2r_client.lock_err("action_client")?)?;
+        is_available.await?;
+        self.request_goal(goal).await
+    }
+
+    /// As `send_goal`, failing when the server is not available or does not answer within `timeout`.
+    pub async fn send_goal_timeout(
+        &self,
+        goal: A::Goal,
+        timeout: Duration,
+    ) -> crate::Result<GoalHandle<A>> {
+        let deadline = Instant::now() + timeout;
+        self.wait_for_server(timeout).await?;
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match crate::availability::timeout(self.request_goal(goal), remaining).await {
+            Some(result) => result,
+            None => Err(format!("goal not answered after {timeout:?}").into()),
+        }
+    }
+
+    async fn request_goal(&self, goal: A::Goal) -> crate::Result<GoalHandle<A>> {
+        let Self::Defined { r2r_client, .. } = self else {
+            return Err("action client not initialized".into());
+        };
+        let request = SendGoalRequest::new(&*r2r_client.lock_err("action_client")?, goal)?;
+        let (r2r_goal, result, feedback) = request.await?;
+        Ok(GoalHandle {
+            r2r_goal: SMutex::create(r2r_goal),
+            result: result.map_err(|e| e.to_string()).boxed().shared(),
+            feedback: SMutex::create(Some(feedback)),
+        })
+    }
+}
+
+type GoalAnswer<A> = r2r::Result<(
+    r2r::ActionClientGoal<A>,
+    BoxFuture<
+        'static,
+        r2r::Result<(
+            r2r::GoalStatus,
+            <A as r2r::WrappedActionTypeSupport>::Result,
+        )>,
+    >,
+    BoxStream<'static, <A as r2r::WrappedActionTypeSupport>::Feedback>,
+)>;
+
+/// Goal request future of `r2r::ActionClient::send_goal_request`, the only future it can hold.
+struct SendGoalRequest<A>(std::pin::Pin<Box<dyn Future<Output = GoalAnswer<A>>>>)
+where
+    A: r2r::WrappedActionTypeSupport;
+
+impl<A> SendGoalRequest<A>
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+    A::Result: Send,
+    A::Feedback: Send,
+{
+    fn new(r2r_client: &r2r::ActionClient<A>, goal: A::Goal) -> r2r::Result<Self> {
+        let request = r2r_client.send_goal_request(goal)?;
+        Ok(Self(Box::pin(request.map(|answer| {
+            answer.map(|(r2r_goal, result, feedback)| (r2r_goal, result.boxed(), feedback.boxed()))
+        }))))
+    }
+}
+
+// SAFETY: r2r's future only holds a oneshot receiver of the goal response and a `Weak` of the
+// client, which r2r already declares `Send` for `r2r::ActionClient` and only accesses under its mutex.
+unsafe impl<A> Send for SendGoalRequest<A> where A: r2r::WrappedActionTypeSupport {}
+
+impl<A> Future for SendGoalRequest<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    type Output = GoalAnswer<A>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+type GoalResult<Res> = std::result::Result<(r2r::GoalStatus, Res), String>;
+
+/// Goal sent by an `ActionClient`.
+pub struct GoalHandle<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    r2r_goal: SMutex<r2r::ActionClientGoal<A>>,
+    result: Shared<BoxFuture<'static, GoalResult<A::Result>>>,
+    feedback: SMutex<Option<BoxStream<'static, A::Feedback>>>,
+}
+
+impl<A> GoalHandle<A>
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+    A::Feedback: Send,
+{
+    pub fn goal_id(&self) -> String {
+        self.r2r_goal.lock_or_log("goal").uuid.to_string()
+    }
+
+    pub fn status(&self) -> crate::Result<r2r::GoalStatus> {
+        Ok(self.r2r_goal.lock_err("goal")?.get_status()?)
+    }
+
+    /// Feedback of the goal, the stream can be taken once.
+    pub fn feedback(&self) -> BoxStream<'static, A::Feedback> {
+        match self.feedback.lock_or_log("feedback").take() {
+            Some(feedback) => feedback,
+            None => futures::stream::empty().boxed(),
+        }
+    }
+
+    /// Final status and result of the goal.
+    pub async fn result(&self) -> crate::Result<(r2r::GoalStatus, A::Result)> {
+        Ok(self.result.clone().await?)
+    }
+
+    /// Final status and result of the goal, waiting at most `timeout`.
+    pub async fn result_timeout(
+        &self,
+        timeout: Duration,
+    ) -> crate::Result<(r2r::GoalStatus, A::Result)> {
+        match crate::availability::timeout(self.result.clone(), timeout).await {
+            Some(result) => Ok(result?),
+            None => Err(format!("goal result not received after {timeout:?}").into()),
+        }
+    }
+
+    /// Requests the cancellation of the goal, fails when the server rejects it.
+    pub async fn cancel(&self) -> crate::Result<()> {
+        let cancel = self.r2r_goal.lock_err("goal")?.cancel()?;
+        cancel.await?;
+        Ok(())
+    }
+}
+
+/// Sends `goal` and calls `feedback_callback` with its feedback until its final status and result,
+/// for the clients of the synchronous nodes.
+pub(crate) async fn follow_goal<A, FF>(
+    client: ActionClient<A>,
+    goal: A::Goal,
+    mut feedback_callback: FF,
+) -> GoalResult<A::Result>
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+    A::Result: Send,
+    A::Feedback: Send,
+    FF: FnMut(A::Feedback),
+{
+    let handle = client.send_goal(goal).await.map_err(|e| e.to_string())?;
+    let feedback = handle.feedback().for_each(move |feedback| {
+        feedback_callback(feedback);
+        futures::future::ready(())
+    });
+    match futures::future::select(handle.result.clone(), feedback).await {
+        Either::Left((result, _)) => result,
+        Either::Right((_, result)) => result.await,
+    }
+}
@@ -1,6 +1,7 @@
 pub trait NodeAsync: Sized {
     type Publisher<M: r2r::WrappedTypesupport>;
     type Client<S: r2r::WrappedServiceTypeSupport>;
+    type UntypedPublisher;
 
     //-------------------------------------------------- Create --------------------------------------------------
 
@@ -14,6 +15,9 @@ pub trait NodeAsync: Sized {
 
     fn now(&self) -> std::time::Duration;
 
+    /// Current time of `clock_type`, `ClockType::Ros` reading the same clock as `now`.
+    fn now_with(&self, clock_type: crate::ClockType) -> crate::Time;
+
     //-------------------------------------------------- Logger --------------------------------------------------
 
     fn logger(&self) -> String;
@@ -152,6 +156,23 @@ pub trait NodeAsync: Sized {
     where
         M: r2r::WrappedTypesupport;
 
+    fn create_publisher_with_options<M>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::PublisherOptions,
+    ) -> crate::Result<Self::Publisher<M>>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport;
+
+    /// Publisher of `topic_type` (e.g. "std_msgs/msg/String") known at runtime, messages are given as JSON.
+    fn create_untyped_publisher(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> crate::Result<Self::UntypedPublisher>;
+
     //-------------------------------------------------- Subscriber --------------------------------------------------
 
     fn create_subscription<M, T, F, R>(
@@ -280,204 +301,195 @@ pub trait NodeAsync: Sized {
         R: Future<Output = ()>,
         R: Send;
 
-    //-------------------------------------------------- Service --------------------------------------------------
-
-    fn create_service<S, T, F, R>(
+    fn create_subscription_with_options_0<M, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
-        data: T,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T: Clone + Send + 'static,
-        F: Send + 'static,
-        F: Fn(T, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-    {
-        self.create_service_1::<S, T, F, R>(service_name, qos_profile, callback, data)
-    }
+        M: Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn(M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_0<S, F, R>(
+    fn create_subscription_with_options_1<M, T, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
+        data: T,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        F: Send + 'static,
-        F: Fn(S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send;
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T, M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_typed_0<S, F, R>(
+    fn create_subscription_with_options_2<M, T1, T2, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
+        data_1: T1,
+        data_2: T2,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        F: Send + 'static,
-        F: Fn(S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-    {
-        self.create_service_0::<S, F, R>(service_name, qos_profile, callback)
-    }
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_1<S, T, F, R>(
+    fn create_subscription_with_options_3<M, T1, T2, T3, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
-        data: T,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T: Clone + Send + 'static,
-        F: Send + 'static,
-        F: Fn(T, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send;
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_typed_1<S, T, F, R>(
+    fn create_subscription_with_options_4<M, T1, T2, T3, T4, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
-        data: T,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T: Clone + Send + 'static,
-        F: Send + 'static,
-        F: Fn(T, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-    {
-        self.create_service_1::<S, T, F, R>(service_name, qos_profile, callback, data)
-    }
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_2<S, T1, T2, F, R>(
+    fn create_subscription_with_options_5<M, T1, T2, T3, T4, T5, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
-        F: Send + 'static,
-        F: Fn(T1, T2, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send;
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        T5: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, T5, M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_typed_2<S, T1, T2, F, R>(
+    /// Subscription also receiving the messages of the in-process publishers, see `PublisherOptions::intra_process`,
+    /// the callbacks sharing each message as an `Arc`.
+    fn create_subscription_shared_0<M, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
-        data_1: T1,
-        data_2: T2,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
-        F: Send + 'static,
-        F: Fn(T1, T2, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-    {
-        self.create_service_2::<S, T1, T2, F, R>(
-            service_name,
-            qos_profile,
-            callback,
-            data_1,
-            data_2,
-        )
-    }
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn(std::sync::Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_3<S, T1, T2, T3, F, R>(
+    fn create_subscription_shared_1<M, T, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
-        data_1: T1,
-        data_2: T2,
-        data_3: T3,
+        data: T,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
-        T3: Clone + Send + 'static,
-        F: Send + 'static,
-        F: Fn(T1, T2, T3, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send;
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T, std::sync::Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_typed_3<S, T1, T2, T3, F, R>(
+    fn create_subscription_shared_2<M, T1, T2, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
-        data_3: T3,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
-        T3: Clone + Send + 'static,
-        F: Send + 'static,
-        F: Fn(T1, T2, T3, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-    {
-        self.create_service_3::<S, T1, T2, T3, F, R>(
-            service_name,
-            qos_profile,
-            callback,
-            data_1,
-            data_2,
-            data_3,
-        )
-    }
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, std::sync::Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_4<S, T1, T2, T3, T4, F, R>(
+    fn create_subscription_shared_3<M, T1, T2, T3, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
         data_3: T3,
-        data_4: T4,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
-        T3: Clone + Send + 'static,
-        T4: Clone + Send + 'static,
-        F: Send + 'static,
-        F: Fn(T1, T2, T3, T4, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send;
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, std::sync::Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_typed_4<S, T1, T2, T3, T4, F, R>(
+    fn create_subscription_shared_4<M, T1, T2, T3, T4, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
@@ -485,31 +497,21 @@ pub trait NodeAsync: Sized {
         data_4: T4,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
-        T3: Clone + Send + 'static,
-        T4: Clone + Send + 'static,
-        F: Send + 'static,
-        F: Fn(T1, T2, T3, T4, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-    {
-        self.create_service_4::<S, T1, T2, T3, T4, F, R>(
-            service_name,
-            qos_profile,
-            callback,
-            data_1,
-            data_2,
-            data_3,
-            data_4,
-        )
-    }
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, std::sync::Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
 
-    fn create_service_5<S, T1, T2, T3, T4, T5, F, R>(
+    fn create_subscription_shared_5<M, T1, T2, T3, T4, T5, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
@@ -518,21 +520,661 @@ pub trait NodeAsync: Sized {
         data_5: T5,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
-        T3: Clone + Send + 'static,
-        T4: Clone + Send + 'static,
-        T5: Clone + Send + 'static,
-        F: Send + 'static,
-        F: Fn(T1, T2, T3, T4, T5, S::Request) -> R,
-        R: Future<Output = S::Response>,
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        T5: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, T5, std::sync::Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static;
+
+    fn create_subscription_with_info_0<M, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn(M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    fn create_subscription_with_info_1<M, T, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T, M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    fn create_subscription_with_info_2<M, T1, T2, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    fn create_subscription_with_info_3<M, T1, T2, T3, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    fn create_subscription_with_info_4<M, T1, T2, T3, T4, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    fn create_subscription_with_info_5<M, T1, T2, T3, T4, T5, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        T5: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, T5, M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    /// Subscription to `topic_type` (e.g. "std_msgs/msg/String") known at runtime, messages are delivered as JSON.
+    fn create_untyped_subscription<F, R>(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        F: Send + Sync + 'static,
+        F: Fn(serde_json::Value) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    /// Subscription delivering the serialized (CDR) messages, without deserializing them.
+    fn create_raw_subscription<F, R>(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        F: Send + Sync + 'static,
+        F: Fn(crate::RawMessage) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    //-------------------------------------------------- Stream --------------------------------------------------
+
+    /// Messages of `topic` as a `Stream`, to be consumed with `StreamExt` combinators or `select!`.
+    fn subscribe_stream<M>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> crate::Result<crate::Subscription<M>>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport;
+
+    //-------------------------------------------------- Synchronizer --------------------------------------------------
+
+    /// Delivers the messages of the topics matched by their header stamps as a tuple.
+    fn create_synchronizer_2<M1, M2, F, R>(
+        &self,
+        topics: [&str; 2],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn((M1, M2)) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    /// Delivers the messages of the topics matched by their header stamps as a tuple.
+    fn create_synchronizer_3<M1, M2, M3, F, R>(
+        &self,
+        topics: [&str; 3],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn((M1, M2, M3)) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    /// Delivers the messages of the topics matched by their header stamps as a tuple.
+    fn create_synchronizer_4<M1, M2, M3, M4, F, R>(
+        &self,
+        topics: [&str; 4],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M4: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn((M1, M2, M3, M4)) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    /// Delivers the messages of the topics matched by their header stamps as a tuple.
+    fn create_synchronizer_5<M1, M2, M3, M4, M5, F, R>(
+        &self,
+        topics: [&str; 5],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M4: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M5: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn((M1, M2, M3, M4, M5)) -> R,
+        R: Future<Output = ()>,
+        R: Send;
+
+    //-------------------------------------------------- Latest --------------------------------------------------
+
+    /// Keeps the most recent message of `topic`, the handle can be passed as `data` to callbacks.
+    fn create_latest<M>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> crate::Result<crate::Latest<M>>
+    where
+        M: Clone + Send + Sync + 'static + r2r::WrappedTypesupport;
+
+    //-------------------------------------------------- Service --------------------------------------------------
+
+    /// Adds a layer to the services and clients created afterwards, see `Layer`.
+    fn add_layer<L: crate::Layer + 'static>(&mut self, layer: L);
+
+    fn create_service<S, T, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+    {
+        self.create_service_1::<S, T, F, R>(service_name, qos_profile, callback, data)
+    }
+
+    fn create_service_0<S, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send;
+
+    fn create_service_typed_0<S, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+    {
+        self.create_service_0::<S, F, R>(service_name, qos_profile, callback)
+    }
+
+    fn create_service_1<S, T, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send;
+
+    fn create_service_typed_1<S, T, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+    {
+        self.create_service_1::<S, T, F, R>(service_name, qos_profile, callback, data)
+    }
+
+    fn create_service_2<S, T1, T2, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send;
+
+    fn create_service_typed_2<S, T1, T2, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+    {
+        self.create_service_2::<S, T1, T2, F, R>(
+            service_name,
+            qos_profile,
+            callback,
+            data_1,
+            data_2,
+        )
+    }
+
+    fn create_service_3<S, T1, T2, T3, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send;
+
+    fn create_service_typed_3<S, T1, T2, T3, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+    {
+        self.create_service_3::<S, T1, T2, T3, F, R>(
+            service_name,
+            qos_profile,
+            callback,
+            data_1,
+            data_2,
+            data_3,
+        )
+    }
+
+    fn create_service_4<S, T1, T2, T3, T4, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, S::Request) -> R,
+        R: Future<Output = S::Response>,
         R: Send;
 
-    fn create_service_typed_5<S, T1, T2, T3, T4, T5, F, R>(
+    fn create_service_typed_4<S, T1, T2, T3, T4, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+    {
+        self.create_service_4::<S, T1, T2, T3, T4, F, R>(
+            service_name,
+            qos_profile,
+            callback,
+            data_1,
+            data_2,
+            data_3,
+            data_4,
+        )
+    }
+
+    fn create_service_5<S, T1, T2, T3, T4, T5, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+        T5: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, T5, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send;
+
+    fn create_service_typed_5<S, T1, T2, T3, T4, T5, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+        T5: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, T5, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+    {
+        self.create_service_5::<S, T1, T2, T3, T4, T5, F, R>(
+            service_name,
+            qos_profile,
+            callback,
+            data_1,
+            data_2,
+            data_3,
+            data_4,
+            data_5,
+        )
+    }
+
+    /// Service whose handler returns a `Result`, errors are logged and handled according to `policy`.
+    fn create_service_try_0<S, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(S::Request) -> R,
+        R: Future<Output = std::result::Result<S::Response, E>>,
+        R: Send,
+        E: std::fmt::Display;
+
+    fn create_service_try_1<S, T, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T, S::Request) -> R,
+        R: Future<Output = std::result::Result<S::Response, E>>,
+        R: Send,
+        E: std::fmt::Display,
+    {
+        self.create_service_try_0::<S, _, R, E>(service_name, qos_profile, policy, move |req| {
+            callback(data.clone(), req)
+        })
+    }
+
+    fn create_service_try_2<S, T1, T2, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, S::Request) -> R,
+        R: Future<Output = std::result::Result<S::Response, E>>,
+        R: Send,
+        E: std::fmt::Display,
+    {
+        self.create_service_try_0::<S, _, R, E>(service_name, qos_profile, policy, move |req| {
+            callback(data_1.clone(), data_2.clone(), req)
+        })
+    }
+
+    fn create_service_try_3<S, T1, T2, T3, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, S::Request) -> R,
+        R: Future<Output = std::result::Result<S::Response, E>>,
+        R: Send,
+        E: std::fmt::Display,
+    {
+        self.create_service_try_0::<S, _, R, E>(service_name, qos_profile, policy, move |req| {
+            callback(data_1.clone(), data_2.clone(), data_3.clone(), req)
+        })
+    }
+
+    fn create_service_try_4<S, T1, T2, T3, T4, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, S::Request) -> R,
+        R: Future<Output = std::result::Result<S::Response, E>>,
+        R: Send,
+        E: std::fmt::Display,
+    {
+        self.create_service_try_0::<S, _, R, E>(service_name, qos_profile, policy, move |req| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                data_4.clone(),
+                req,
+            )
+        })
+    }
+
+    fn create_service_try_5<S, T1, T2, T3, T4, T5, F, R, E>(
         &self,
         service_name: &str,
         qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
         callback: F,
         data_1: T1,
         data_2: T2,
@@ -549,18 +1191,192 @@ pub trait NodeAsync: Sized {
         T5: Clone + Send + 'static,
         F: Send + 'static,
         F: Fn(T1, T2, T3, T4, T5, S::Request) -> R,
-        R: Future<Output = S::Response>,
+        R: Future<Output = std::result::Result<S::Response, E>>,
         R: Send,
+        E: std::fmt::Display,
     {
-        self.create_service_5::<S, T1, T2, T3, T4, T5, F, R>(
+        self.create_service_try_0::<S, _, R, E>(service_name, qos_profile, policy, move |req| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                data_4.clone(),
+                data_5.clone(),
+                req,
+            )
+        })
+    }
+
+    /// Service handling up to `max_concurrent` requests at the same time,
+    /// each response going back to its own requester.
+    fn create_service_concurrent_0<S, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        max_concurrent: usize,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send + 'static;
+
+    fn create_service_concurrent_1<S, T, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        max_concurrent: usize,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send + 'static,
+    {
+        self.create_service_concurrent_0::<S, _, R>(
             service_name,
             qos_profile,
-            callback,
-            data_1,
-            data_2,
-            data_3,
-            data_4,
-            data_5,
+            max_concurrent,
+            move |req| callback(data.clone(), req),
+        )
+    }
+
+    fn create_service_concurrent_2<S, T1, T2, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        max_concurrent: usize,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send + 'static,
+    {
+        self.create_service_concurrent_0::<S, _, R>(
+            service_name,
+            qos_profile,
+            max_concurrent,
+            move |req| callback(data_1.clone(), data_2.clone(), req),
+        )
+    }
+
+    fn create_service_concurrent_3<S, T1, T2, T3, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        max_concurrent: usize,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send + 'static,
+    {
+        self.create_service_concurrent_0::<S, _, R>(
+            service_name,
+            qos_profile,
+            max_concurrent,
+            move |req| callback(data_1.clone(), data_2.clone(), data_3.clone(), req),
+        )
+    }
+
+    fn create_service_concurrent_4<S, T1, T2, T3, T4, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        max_concurrent: usize,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send + 'static,
+    {
+        self.create_service_concurrent_0::<S, _, R>(
+            service_name,
+            qos_profile,
+            max_concurrent,
+            move |req| {
+                callback(
+                    data_1.clone(),
+                    data_2.clone(),
+                    data_3.clone(),
+                    data_4.clone(),
+                    req,
+                )
+            },
+        )
+    }
+
+    fn create_service_concurrent_5<S, T1, T2, T3, T4, T5, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        max_concurrent: usize,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+        T5: Clone + Send + 'static,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, T5, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send + 'static,
+    {
+        self.create_service_concurrent_0::<S, _, R>(
+            service_name,
+            qos_profile,
+            max_concurrent,
+            move |req| {
+                callback(
+                    data_1.clone(),
+                    data_2.clone(),
+                    data_3.clone(),
+                    data_4.clone(),
+                    data_5.clone(),
+                    req,
+                )
+            },
         )
     }
 
@@ -574,5 +1390,216 @@ pub trait NodeAsync: Sized {
     where
         S: 'static + r2r::WrappedServiceTypeSupport;
 
+    //-------------------------------------------------- Action --------------------------------------------------
+
+    /// Action server: `goal_callback` accepts or rejects the goals, `cancel_callback` the cancel requests,
+    /// and `callback` executes each accepted goal until its outcome, the goals running concurrently.
+    fn create_action_server_0<A, G, C, F, R>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        G: Send + 'static,
+        G: Fn(&A::Goal) -> bool,
+        C: Send + Sync + 'static,
+        C: Fn(&A::Goal) -> bool,
+        F: Send + 'static,
+        F: Fn(crate::ActionGoal<A>) -> R,
+        R: Future<Output = crate::GoalOutcome<A::Result>>,
+        R: Send + 'static;
+
+    fn create_action_server_1<A, T, G, C, F, R>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        T: Clone + Send + 'static,
+        G: Send + 'static,
+        G: Fn(&A::Goal) -> bool,
+        C: Send + Sync + 'static,
+        C: Fn(&A::Goal) -> bool,
+        F: Send + 'static,
+        F: Fn(T, crate::ActionGoal<A>) -> R,
+        R: Future<Output = crate::GoalOutcome<A::Result>>,
+        R: Send + 'static,
+    {
+        self.create_action_server_0::<A, _, _, _, R>(
+            action_name,
+            goal_callback,
+            cancel_callback,
+            move |goal| callback(data.clone(), goal),
+        )
+    }
+
+    fn create_action_server_2<A, T1, T2, G, C, F, R>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        G: Send + 'static,
+        G: Fn(&A::Goal) -> bool,
+        C: Send + Sync + 'static,
+        C: Fn(&A::Goal) -> bool,
+        F: Send + 'static,
+        F: Fn(T1, T2, crate::ActionGoal<A>) -> R,
+        R: Future<Output = crate::GoalOutcome<A::Result>>,
+        R: Send + 'static,
+    {
+        self.create_action_server_0::<A, _, _, _, R>(
+            action_name,
+            goal_callback,
+            cancel_callback,
+            move |goal| callback(data_1.clone(), data_2.clone(), goal),
+        )
+    }
+
+    fn create_action_server_3<A, T1, T2, T3, G, C, F, R>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        G: Send + 'static,
+        G: Fn(&A::Goal) -> bool,
+        C: Send + Sync + 'static,
+        C: Fn(&A::Goal) -> bool,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, crate::ActionGoal<A>) -> R,
+        R: Future<Output = crate::GoalOutcome<A::Result>>,
+        R: Send + 'static,
+    {
+        self.create_action_server_0::<A, _, _, _, R>(
+            action_name,
+            goal_callback,
+            cancel_callback,
+            move |goal| callback(data_1.clone(), data_2.clone(), data_3.clone(), goal),
+        )
+    }
+
+    fn create_action_server_4<A, T1, T2, T3, T4, G, C, F, R>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+        G: Send + 'static,
+        G: Fn(&A::Goal) -> bool,
+        C: Send + Sync + 'static,
+        C: Fn(&A::Goal) -> bool,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, crate::ActionGoal<A>) -> R,
+        R: Future<Output = crate::GoalOutcome<A::Result>>,
+        R: Send + 'static,
+    {
+        self.create_action_server_0::<A, _, _, _, R>(
+            action_name,
+            goal_callback,
+            cancel_callback,
+            move |goal| {
+                callback(
+                    data_1.clone(),
+                    data_2.clone(),
+                    data_3.clone(),
+                    data_4.clone(),
+                    goal,
+                )
+            },
+        )
+    }
+
+    fn create_action_server_5<A, T1, T2, T3, T4, T5, G, C, F, R>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+        T5: Clone + Send + 'static,
+        G: Send + 'static,
+        G: Fn(&A::Goal) -> bool,
+        C: Send + Sync + 'static,
+        C: Fn(&A::Goal) -> bool,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, T5, crate::ActionGoal<A>) -> R,
+        R: Future<Output = crate::GoalOutcome<A::Result>>,
+        R: Send + 'static,
+    {
+        self.create_action_server_0::<A, _, _, _, R>(
+            action_name,
+            goal_callback,
+            cancel_callback,
+            move |goal| {
+                callback(
+                    data_1.clone(),
+                    data_2.clone(),
+                    data_3.clone(),
+                    data_4.clone(),
+                    data_5.clone(),
+                    goal,
+                )
+            },
+        )
+    }
+
+    fn create_action_client<A>(&self, action_name: &str) -> crate::Result<crate::ActionClient<A>>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        A::Feedback: Send;
+
     fn spin(&mut self, timeout: std::time::Duration);
 }
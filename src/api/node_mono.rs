@@ -1,6 +1,8 @@
 pub trait NodeMono: Sized {
     type Publisher<M: r2r::WrappedTypesupport>;
     type Client<S: r2r::WrappedServiceTypeSupport>;
+    type UntypedPublisher;
+    type ActionClient<A: r2r::WrappedActionTypeSupport>;
 
     //-------------------------------------------------- Create --------------------------------------------------
 
@@ -14,6 +16,9 @@ pub trait NodeMono: Sized {
 
     fn now(&self) -> std::time::Duration;
 
+    /// Current time of `clock_type`, `ClockType::Ros` reading the same clock as `now`.
+    fn now_with(&self, clock_type: crate::ClockType) -> crate::Time;
+
     //-------------------------------------------------- Logger --------------------------------------------------
 
     fn logger(&self) -> String;
@@ -127,6 +132,23 @@ pub trait NodeMono: Sized {
     where
         M: r2r::WrappedTypesupport;
 
+    fn create_publisher_with_options<M>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::PublisherOptions,
+    ) -> crate::Result<Self::Publisher<M>>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport;
+
+    /// Publisher of `topic_type` (e.g. "std_msgs/msg/String") known at runtime, messages are given as JSON.
+    fn create_untyped_publisher(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> crate::Result<Self::UntypedPublisher>;
+
     //-------------------------------------------------- Subscriber --------------------------------------------------
 
     fn create_subscription<M, T, F>(
@@ -234,232 +256,273 @@ pub trait NodeMono: Sized {
         T5: Clone + 'static,
         F: 'static + Fn(T1, T2, T3, T4, T5, M);
 
-    //-------------------------------------------------- Service --------------------------------------------------
+    fn create_subscription_with_options_0<M, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        F: 'static + Fn(M);
 
-    fn create_service<S, T, F>(
+    fn create_subscription_with_options_1<M, T, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data: T,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + 'static + r2r::WrappedTypesupport,
         T: Clone + 'static,
-        F: 'static + Fn(T, S::Request) -> S::Response,
-    {
-        self.create_service_1::<S, T, F>(service_name, qos_profile, callback, data)
-    }
+        F: 'static + Fn(T, M);
 
-    fn create_service_0<S, F>(
+    fn create_subscription_with_options_2<M, T1, T2, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
+        data_1: T1,
+        data_2: T2,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        F: 'static + Fn(S::Request) -> S::Response;
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        F: 'static + Fn(T1, T2, M);
 
-    fn create_service_typed_0<S, F, R>(
+    fn create_subscription_with_options_3<M, T1, T2, T3, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        F: 'static + Fn(S::Request) -> R,
-        R: Into<S::Response>,
-    {
-        self.create_service_0::<S, _>(service_name, qos_profile, move |req| callback(req).into())
-    }
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, M);
 
-    fn create_service_1<S, T, F>(
+    fn create_subscription_with_options_4<M, T1, T2, T3, T4, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
-        data: T,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        T: Clone + 'static,
-        F: 'static + Fn(T, S::Request) -> S::Response;
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, M);
 
-    fn create_service_typed_1<S, T, F, R>(
+    fn create_subscription_with_options_5<M, T1, T2, T3, T4, T5, F>(
         &self,
-        service_name: &str,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        T5: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, T5, M);
+
+    /// Subscription also receiving the messages of the in-process publishers, see `PublisherOptions::intra_process`,
+    /// the callbacks sharing each message as an `Arc`.
+    fn create_subscription_shared_0<M, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        F: 'static + Fn(std::sync::Arc<M>);
+
+    fn create_subscription_shared_1<M, T, F>(
+        &self,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data: T,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
         T: Clone + 'static,
-        F: 'static + Fn(T, S::Request) -> R,
-        R: Into<S::Response>,
-    {
-        self.create_service_1::<S, T, _>(
-            service_name,
-            qos_profile,
-            move |v, req| callback(v, req).into(),
-            data,
-        )
-    }
+        F: 'static + Fn(T, std::sync::Arc<M>);
 
-    fn create_service_2<S, T1, T2, F>(
+    fn create_subscription_shared_2<M, T1, T2, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
         T1: Clone + 'static,
         T2: Clone + 'static,
-        F: 'static + Fn(T1, T2, S::Request) -> S::Response;
+        F: 'static + Fn(T1, T2, std::sync::Arc<M>);
 
-    fn create_service_typed_2<S, T1, T2, F, R>(
+    fn create_subscription_shared_3<M, T1, T2, T3, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
+        data_3: T3,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
         T1: Clone + 'static,
         T2: Clone + 'static,
-        F: 'static + Fn(T1, T2, S::Request) -> R,
-        R: Into<S::Response>,
-    {
-        self.create_service_2::<S, T1, T2, _>(
-            service_name,
-            qos_profile,
-            move |v1, v2, req| callback(v1, v2, req).into(),
-            data_1,
-            data_2,
-        )
-    }
+        T3: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, std::sync::Arc<M>);
 
-    fn create_service_3<S, T1, T2, T3, F>(
+    fn create_subscription_shared_4<M, T1, T2, T3, T4, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
         data_3: T3,
+        data_4: T4,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
         T1: Clone + 'static,
         T2: Clone + 'static,
         T3: Clone + 'static,
-        F: 'static + Fn(T1, T2, T3, S::Request) -> S::Response;
+        T4: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, std::sync::Arc<M>);
 
-    fn create_service_typed_3<S, T1, T2, T3, F, R>(
+    fn create_subscription_shared_5<M, T1, T2, T3, T4, T5, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
         data_3: T3,
+        data_4: T4,
+        data_5: T5,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
         T1: Clone + 'static,
         T2: Clone + 'static,
         T3: Clone + 'static,
-        F: 'static + Fn(T1, T2, T3, S::Request) -> R,
-        R: Into<S::Response>,
-    {
-        self.create_service_3::<S, T1, T2, T3, _>(
-            service_name,
-            qos_profile,
-            move |v1, v2, v3, req| callback(v1, v2, v3, req).into(),
-            data_1,
-            data_2,
-            data_3,
-        )
-    }
+        T4: Clone + 'static,
+        T5: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, T5, std::sync::Arc<M>);
 
-    fn create_service_4<S, T1, T2, T3, T4, F>(
+    fn create_subscription_with_info_0<M, F>(
         &self,
-        service_name: &str,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        F: 'static + Fn(M, crate::ReceiveInfo);
+
+    fn create_subscription_with_info_1<M, T, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T: Clone + 'static,
+        F: 'static + Fn(T, M, crate::ReceiveInfo);
+
+    fn create_subscription_with_info_2<M, T1, T2, F>(
+        &self,
+        topic: &str,
         qos_profile: r2r::QosProfile,
         callback: F,
         data_1: T1,
         data_2: T2,
-        data_3: T3,
-        data_4: T4,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + 'static + r2r::WrappedTypesupport,
         T1: Clone + 'static,
         T2: Clone + 'static,
-        T3: Clone + 'static,
-        T4: Clone + 'static,
-        F: 'static + Fn(T1, T2, T3, T4, S::Request) -> S::Response;
+        F: 'static + Fn(T1, T2, M, crate::ReceiveInfo);
 
-    fn create_service_typed_4<S, T1, T2, T3, T4, F, R>(
+    fn create_subscription_with_info_3<M, T1, T2, T3, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
         callback: F,
         data_1: T1,
         data_2: T2,
         data_3: T3,
-        data_4: T4,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + 'static + r2r::WrappedTypesupport,
         T1: Clone + 'static,
         T2: Clone + 'static,
         T3: Clone + 'static,
-        T4: Clone + 'static,
-        F: 'static + Fn(T1, T2, T3, T4, S::Request) -> R,
-        R: Into<S::Response>,
-    {
-        self.create_service_4::<S, T1, T2, T3, T4, _>(
-            service_name,
-            qos_profile,
-            move |v1, v2, v3, v4, req| callback(v1, v2, v3, v4, req).into(),
-            data_1,
-            data_2,
-            data_3,
-            data_4,
-        )
-    }
+        F: 'static + Fn(T1, T2, T3, M, crate::ReceiveInfo);
 
-    fn create_service_5<S, T1, T2, T3, T4, T5, F>(
+    fn create_subscription_with_info_4<M, T1, T2, T3, T4, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
         callback: F,
         data_1: T1,
         data_2: T2,
         data_3: T3,
         data_4: T4,
-        data_5: T5,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + 'static + r2r::WrappedTypesupport,
         T1: Clone + 'static,
         T2: Clone + 'static,
         T3: Clone + 'static,
         T4: Clone + 'static,
-        T5: Clone + 'static,
-        F: 'static + Fn(T1, T2, T3, T4, T5, S::Request) -> S::Response;
+        F: 'static + Fn(T1, T2, T3, T4, M, crate::ReceiveInfo);
 
-    fn create_service_typed_5<S, T1, T2, T3, T4, T5, F, R>(
+    fn create_subscription_with_info_5<M, T1, T2, T3, T4, T5, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
         callback: F,
         data_1: T1,
@@ -469,36 +532,835 @@ pub trait NodeMono: Sized {
         data_5: T5,
     ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + 'static + r2r::WrappedTypesupport,
         T1: Clone + 'static,
         T2: Clone + 'static,
         T3: Clone + 'static,
         T4: Clone + 'static,
         T5: Clone + 'static,
-        F: 'static + Fn(T1, T2, T3, T4, T5, S::Request) -> R,
-        R: Into<S::Response>,
-    {
-        self.create_service_5::<S, T1, T2, T3, T4, T5, _>(
-            service_name,
-            qos_profile,
-            move |v1, v2, v3, v4, v5, req| callback(v1, v2, v3, v4, v5, req).into(),
-            data_1,
-            data_2,
-            data_3,
-            data_4,
-            data_5,
-        )
-    }
+        F: 'static + Fn(T1, T2, T3, T4, T5, M, crate::ReceiveInfo);
 
-    //-------------------------------------------------- Client --------------------------------------------------
+    /// Subscription to `topic_type` (e.g. "std_msgs/msg/String") known at runtime, messages are delivered as JSON.
+    fn create_untyped_subscription<F>(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        F: 'static + Fn(serde_json::Value);
 
-    fn create_client<S>(
+    /// Subscription delivering the serialized (CDR) messages, without deserializing them.
+    fn create_raw_subscription<F>(
         &self,
-        service_name: &str,
+        topic: &str,
+        topic_type: &str,
         qos_profile: r2r::QosProfile,
-    ) -> crate::Result<Self::Client<S>>
+        callback: F,
+    ) -> crate::Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport;
+        F: 'static + Fn(crate::RawMessage);
+
+    //-------------------------------------------------- Synchronizer --------------------------------------------------
+
+    /// Delivers the messages of the topics matched by their header stamps as a tuple.
+    fn create_synchronizer_2<M1, M2, F>(
+        &self,
+        topics: [&str; 2],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: 'static + Fn((M1, M2));
+
+    /// Delivers the messages of the topics matched by their header stamps as a tuple.
+    fn create_synchronizer_3<M1, M2, M3, F>(
+        &self,
+        topics: [&str; 3],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: 'static + Fn((M1, M2, M3));
+
+    /// Delivers the messages of the topics matched by their header stamps as a tuple.
+    fn create_synchronizer_4<M1, M2, M3, M4, F>(
+        &self,
+        topics: [&str; 4],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M4: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: 'static + Fn((M1, M2, M3, M4));
+
+    /// Delivers the messages of the topics matched by their header stamps as a tuple.
+    fn create_synchronizer_5<M1, M2, M3, M4, M5, F>(
+        &self,
+        topics: [&str; 5],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M4: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M5: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: 'static + Fn((M1, M2, M3, M4, M5));
+
+    //-------------------------------------------------- Latest --------------------------------------------------
+
+    /// Keeps the most recent message of `topic`, the handle can be passed as `data` to callbacks.
+    fn create_latest<M>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> crate::Result<crate::Latest<M>>
+    where
+        M: Clone + Send + Sync + 'static + r2r::WrappedTypesupport;
+
+    //-------------------------------------------------- Service --------------------------------------------------
+
+    /// Adds a layer to the services and clients created afterwards, see `Layer`.
+    fn add_layer<L: crate::Layer + 'static>(&mut self, layer: L);
+
+    fn create_service<S, T, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T: Clone + 'static,
+        F: 'static + Fn(T, S::Request) -> S::Response,
+    {
+        self.create_service_1::<S, T, F>(service_name, qos_profile, callback, data)
+    }
+
+    fn create_service_0<S, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: 'static + Fn(S::Request) -> S::Response;
+
+    fn create_service_typed_0<S, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: 'static + Fn(S::Request) -> R,
+        R: Into<S::Response>,
+    {
+        self.create_service_0::<S, _>(service_name, qos_profile, move |req| callback(req).into())
+    }
+
+    fn create_service_1<S, T, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T: Clone + 'static,
+        F: 'static + Fn(T, S::Request) -> S::Response;
+
+    fn create_service_typed_1<S, T, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T: Clone + 'static,
+        F: 'static + Fn(T, S::Request) -> R,
+        R: Into<S::Response>,
+    {
+        self.create_service_1::<S, T, _>(
+            service_name,
+            qos_profile,
+            move |v, req| callback(v, req).into(),
+            data,
+        )
+    }
+
+    fn create_service_2<S, T1, T2, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        F: 'static + Fn(T1, T2, S::Request) -> S::Response;
+
+    fn create_service_typed_2<S, T1, T2, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        F: 'static + Fn(T1, T2, S::Request) -> R,
+        R: Into<S::Response>,
+    {
+        self.create_service_2::<S, T1, T2, _>(
+            service_name,
+            qos_profile,
+            move |v1, v2, req| callback(v1, v2, req).into(),
+            data_1,
+            data_2,
+        )
+    }
+
+    fn create_service_3<S, T1, T2, T3, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, S::Request) -> S::Response;
+
+    fn create_service_typed_3<S, T1, T2, T3, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, S::Request) -> R,
+        R: Into<S::Response>,
+    {
+        self.create_service_3::<S, T1, T2, T3, _>(
+            service_name,
+            qos_profile,
+            move |v1, v2, v3, req| callback(v1, v2, v3, req).into(),
+            data_1,
+            data_2,
+            data_3,
+        )
+    }
+
+    fn create_service_4<S, T1, T2, T3, T4, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, S::Request) -> S::Response;
+
+    fn create_service_typed_4<S, T1, T2, T3, T4, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, S::Request) -> R,
+        R: Into<S::Response>,
+    {
+        self.create_service_4::<S, T1, T2, T3, T4, _>(
+            service_name,
+            qos_profile,
+            move |v1, v2, v3, v4, req| callback(v1, v2, v3, v4, req).into(),
+            data_1,
+            data_2,
+            data_3,
+            data_4,
+        )
+    }
+
+    fn create_service_5<S, T1, T2, T3, T4, T5, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        T5: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, T5, S::Request) -> S::Response;
+
+    fn create_service_typed_5<S, T1, T2, T3, T4, T5, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        T5: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, T5, S::Request) -> R,
+        R: Into<S::Response>,
+    {
+        self.create_service_5::<S, T1, T2, T3, T4, T5, _>(
+            service_name,
+            qos_profile,
+            move |v1, v2, v3, v4, v5, req| callback(v1, v2, v3, v4, v5, req).into(),
+            data_1,
+            data_2,
+            data_3,
+            data_4,
+            data_5,
+        )
+    }
+
+    /// Service whose handler returns a `Result`, errors are logged and handled according to `policy`.
+    fn create_service_try_0<S, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: 'static + Fn(S::Request) -> R,
+        R: Into<std::result::Result<S::Response, E>>,
+        E: std::fmt::Display;
+
+    fn create_service_try_1<S, T, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T: Clone + 'static,
+        F: 'static + Fn(T, S::Request) -> R,
+        R: Into<std::result::Result<S::Response, E>>,
+        E: std::fmt::Display,
+    {
+        self.create_service_try_0::<S, _, R, E>(service_name, qos_profile, policy, move |req| {
+            callback(data.clone(), req)
+        })
+    }
+
+    fn create_service_try_2<S, T1, T2, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        F: 'static + Fn(T1, T2, S::Request) -> R,
+        R: Into<std::result::Result<S::Response, E>>,
+        E: std::fmt::Display,
+    {
+        self.create_service_try_0::<S, _, R, E>(service_name, qos_profile, policy, move |req| {
+            callback(data_1.clone(), data_2.clone(), req)
+        })
+    }
+
+    fn create_service_try_3<S, T1, T2, T3, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, S::Request) -> R,
+        R: Into<std::result::Result<S::Response, E>>,
+        E: std::fmt::Display,
+    {
+        self.create_service_try_0::<S, _, R, E>(service_name, qos_profile, policy, move |req| {
+            callback(data_1.clone(), data_2.clone(), data_3.clone(), req)
+        })
+    }
+
+    fn create_service_try_4<S, T1, T2, T3, T4, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, S::Request) -> R,
+        R: Into<std::result::Result<S::Response, E>>,
+        E: std::fmt::Display,
+    {
+        self.create_service_try_0::<S, _, R, E>(service_name, qos_profile, policy, move |req| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                data_4.clone(),
+                req,
+            )
+        })
+    }
+
+    fn create_service_try_5<S, T1, T2, T3, T4, T5, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        T5: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, T5, S::Request) -> R,
+        R: Into<std::result::Result<S::Response, E>>,
+        E: std::fmt::Display,
+    {
+        self.create_service_try_0::<S, _, R, E>(service_name, qos_profile, policy, move |req| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                data_4.clone(),
+                data_5.clone(),
+                req,
+            )
+        })
+    }
+
+    /// Service whose handler gets a `Responder` to answer the request later, e.g. from a client callback.
+    fn create_service_deferred_0<S, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: 'static + Fn(S::Request, crate::Responder<S>);
+
+    fn create_service_deferred_1<S, T, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T: Clone + 'static,
+        F: 'static + Fn(T, S::Request, crate::Responder<S>),
+    {
+        self.create_service_deferred_0::<S, _>(service_name, qos_profile, move |req, responder| {
+            callback(data.clone(), req, responder)
+        })
+    }
+
+    fn create_service_deferred_2<S, T1, T2, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        F: 'static + Fn(T1, T2, S::Request, crate::Responder<S>),
+    {
+        self.create_service_deferred_0::<S, _>(service_name, qos_profile, move |req, responder| {
+            callback(data_1.clone(), data_2.clone(), req, responder)
+        })
+    }
+
+    fn create_service_deferred_3<S, T1, T2, T3, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, S::Request, crate::Responder<S>),
+    {
+        self.create_service_deferred_0::<S, _>(service_name, qos_profile, move |req, responder| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                req,
+                responder,
+            )
+        })
+    }
+
+    fn create_service_deferred_4<S, T1, T2, T3, T4, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, S::Request, crate::Responder<S>),
+    {
+        self.create_service_deferred_0::<S, _>(service_name, qos_profile, move |req, responder| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                data_4.clone(),
+                req,
+                responder,
+            )
+        })
+    }
+
+    fn create_service_deferred_5<S, T1, T2, T3, T4, T5, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        T5: Clone + 'static,
+        F: 'static + Fn(T1, T2, T3, T4, T5, S::Request, crate::Responder<S>),
+    {
+        self.create_service_deferred_0::<S, _>(service_name, qos_profile, move |req, responder| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                data_4.clone(),
+                data_5.clone(),
+                req,
+                responder,
+            )
+        })
+    }
+
+    //-------------------------------------------------- Client --------------------------------------------------
+
+    fn create_client<S>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> crate::Result<Self::Client<S>>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport;
+
+    //-------------------------------------------------- Action --------------------------------------------------
+
+    /// Action server: `goal_callback` accepts or rejects the goals, `cancel_callback` the cancel requests,
+    /// and `callback` starts each accepted goal, which ends once `ActionGoal::complete` is called,
+    /// e.g. from a timer: the callback runs on the node thread and must not block.
+    fn create_action_server_0<A, G, C, F>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        G: 'static + Fn(&A::Goal) -> bool,
+        C: 'static + Fn(&A::Goal) -> bool,
+        F: 'static + Fn(crate::ActionGoal<A>);
+
+    fn create_action_server_1<A, T, G, C, F>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+        data: T,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        T: Clone + 'static,
+        G: 'static + Fn(&A::Goal) -> bool,
+        C: 'static + Fn(&A::Goal) -> bool,
+        F: 'static + Fn(T, crate::ActionGoal<A>),
+    {
+        self.create_action_server_0::<A, _, _, _>(
+            action_name,
+            goal_callback,
+            cancel_callback,
+            move |goal| callback(data.clone(), goal),
+        )
+    }
+
+    fn create_action_server_2<A, T1, T2, G, C, F>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        G: 'static + Fn(&A::Goal) -> bool,
+        C: 'static + Fn(&A::Goal) -> bool,
+        F: 'static + Fn(T1, T2, crate::ActionGoal<A>),
+    {
+        self.create_action_server_0::<A, _, _, _>(
+            action_name,
+            goal_callback,
+            cancel_callback,
+            move |goal| callback(data_1.clone(), data_2.clone(), goal),
+        )
+    }
+
+    fn create_action_server_3<A, T1, T2, T3, G, C, F>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        G: 'static + Fn(&A::Goal) -> bool,
+        C: 'static + Fn(&A::Goal) -> bool,
+        F: 'static + Fn(T1, T2, T3, crate::ActionGoal<A>),
+    {
+        self.create_action_server_0::<A, _, _, _>(
+            action_name,
+            goal_callback,
+            cancel_callback,
+            move |goal| callback(data_1.clone(), data_2.clone(), data_3.clone(), goal),
+        )
+    }
+
+    fn create_action_server_4<A, T1, T2, T3, T4, G, C, F>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        G: 'static + Fn(&A::Goal) -> bool,
+        C: 'static + Fn(&A::Goal) -> bool,
+        F: 'static + Fn(T1, T2, T3, T4, crate::ActionGoal<A>),
+    {
+        self.create_action_server_0::<A, _, _, _>(
+            action_name,
+            goal_callback,
+            cancel_callback,
+            move |goal| {
+                callback(
+                    data_1.clone(),
+                    data_2.clone(),
+                    data_3.clone(),
+                    data_4.clone(),
+                    goal,
+                )
+            },
+        )
+    }
+
+    fn create_action_server_5<A, T1, T2, T3, T4, T5, G, C, F>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> crate::Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        T1: Clone + 'static,
+        T2: Clone + 'static,
+        T3: Clone + 'static,
+        T4: Clone + 'static,
+        T5: Clone + 'static,
+        G: 'static + Fn(&A::Goal) -> bool,
+        C: 'static + Fn(&A::Goal) -> bool,
+        F: 'static + Fn(T1, T2, T3, T4, T5, crate::ActionGoal<A>),
+    {
+        self.create_action_server_0::<A, _, _, _>(
+            action_name,
+            goal_callback,
+            cancel_callback,
+            move |goal| {
+                callback(
+                    data_1.clone(),
+                    data_2.clone(),
+                    data_3.clone(),
+                    data_4.clone(),
+                    data_5.clone(),
+                    goal,
+                )
+            },
+        )
+    }
+
+    fn create_action_client<A>(&self, action_name: &str) -> crate::Result<Self::ActionClient<A>>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        A::Feedback: Send;
 
     fn spin(&mut self, timeout: std::time::Duration);
 }
@@ -0,0 +1,287 @@
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Arc, Condvar, LazyLock, Mutex, PoisonError, Weak};
+use std::time::{Duration, Instant};
+
+use futures::FutureExt;
+use futures::channel::oneshot;
+use futures::future::Either;
+
+use crate::blocking::AVAILABILITY_SPIN;
+use crate::{MutexCreate, MutexLockOrLog, SMutex};
+
+/// Period of the availability checks of a client.
+pub(crate) const WATCH_PERIOD: Duration = Duration::from_millis(500);
+
+type Callback = Arc<dyn Fn(bool) + Send + Sync>;
+
+/// Availability of the service of a client, cheap to clone.
+///
+/// Its watcher, see `watch`, starts on first use.
+#[derive(Clone)]
+pub struct Availability {
+    state: Arc<State>,
+    start: SMutex<Option<oneshot::Sender<()>>>,
+}
+
+/// State shared by an `Availability` and its watcher.
+struct State {
+    sender: tokio::sync::watch::Sender<bool>,
+    callbacks: SMutex<Vec<Callback>>,
+}
+
+impl State {
+    fn set(&self, ready: bool) {
+        if self.sender.send_replace(ready) != ready {
+            let callbacks = self.callbacks.lock_or_log("availability").clone();
+            for callback in callbacks {
+                callback(ready);
+            }
+        }
+    }
+}
+
+/// Watcher of an `Availability`, to be run by `watch`.
+pub(crate) struct Watcher {
+    state: Arc<State>,
+    started: oneshot::Receiver<()>,
+}
+
+impl Availability {
+    pub(crate) fn new() -> (Self, Watcher) {
+        let state = Arc::new(State {
+            sender: tokio::sync::watch::Sender::new(false),
+            callbacks: SMutex::create(Vec::new()),
+        });
+        let (start, started) = oneshot::channel();
+        let availability = Self {
+            state: state.clone(),
+            start: SMutex::create(Some(start)),
+        };
+        (availability, Watcher { state, started })
+    }
+
+    fn start(&self) {
+        if let Some(start) = self.start.lock_or_log("availability").take() {
+            let _ = start.send(());
+        }
+    }
+
+    pub(crate) fn is_ready(&self) -> bool {
+        self.start();
+        *self.state.sender.borrow()
+    }
+
+    pub(crate) fn set(&self, ready: bool) {
+        self.state.set(ready);
+    }
+
+    pub(crate) fn on_change<F>(&self, callback: F)
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.start();
+        self.state
+            .callbacks
+            .lock_or_log("availability")
+            .push(Arc::new(callback));
+    }
+
+    /// Completes once the service is available.
+    pub(crate) async fn wait(&self) {
+        self.start();
+        let mut receiver = self.state.sender.subscribe();
+        let _ = receiver.wait_for(|ready| *ready).await;
+    }
+}
+
+/// Tracks the availability of the service of `r2r_client` until the client is dropped.
+///
+/// The checks start on the first use of the availability, a client dropped before ends the watch.
+/// r2r only tells when a service becomes available,
+/// it is considered lost when a new check is not answered within a timer period.
+pub(crate) async fn watch<S>(
+    r2r_client: Weak<r2r::Client<S>>,
+    r2r_node: SMutex<r2r::Node>,
+    watcher: Watcher,
+) where
+    S: 'static + r2r::WrappedServiceTypeSupport,
+{
+    let Watcher { state, started } = watcher;
+    if started.await.is_err() {
+        return;
+    }
+    let timer = r2r_node
+        .lock_or_log("r2r_node")
+        .create_wall_timer(WATCH_PERIOD);
+    let mut timer = match timer {
+        Ok(timer) => timer,
+        Err(e) => {
+            r2r::log_error!("", "service availability timer error: {}", e);
+            return;
+        }
+    };
+
+    let mut is_available = None;
+    loop {
+        let check = match is_available.take() {
+            Some(check) => check,
+            None => {
+                let Some(r2r_client) = r2r_client.upgrade() else {
+                    return;
+                };
+                match r2r::Node::is_available(&*r2r_client) {
+                    Ok(check) => check.boxed(),
+                    Err(e) => {
+                        r2r::log_error!("", "service availability error: {}", e);
+                        return;
+                    }
+                }
+            }
+        };
+        let outcome = match futures::future::select(check, pin!(timer.tick())).await {
+            Either::Left((result, _)) => Either::Left(result.is_ok()),
+            Either::Right((tick, check)) => Either::Right((tick.is_ok(), check)),
+        };
+        match outcome {
+            Either::Left(ready) => {
+                state.set(ready);
+                if timer.tick().await.is_err() {
+                    return;
+                }
+            }
+            Either::Right((ticking, check)) => {
+                state.set(false);
+                if !ticking || r2r_client.strong_count() == 0 {
+                    return;
+                }
+                is_available = Some(check);
+            }
+        }
+    }
+}
+
+/// Pending `sleep`s with their deadline.
+type Sleeps = Mutex<Vec<(Instant, oneshot::Sender<()>)>>;
+
+/// Pending `sleep`s, completed by a single timer thread.
+static SLEEPS: LazyLock<(Sleeps, Condvar)> = LazyLock::new(|| {
+    std::thread::Builder::new()
+        .name("rutile_r2r timer".to_string())
+        .spawn(run_sleeps)
+        .expect("timer thread");
+    (Mutex::new(Vec::new()), Condvar::new())
+});
+
+/// Completes after `duration`, without depending on an async runtime.
+pub(crate) fn sleep(duration: Duration) -> impl Future<Output = ()> {
+    let (sender, receiver) = oneshot::channel();
+    let (sleeps, wakeup) = &*SLEEPS;
+    sleeps
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push((Instant::now() + duration, sender));
+    wakeup.notify_one();
+    receiver.map(|_| ())
+}
+
+/// Completes the due and abandoned sleeps, then waits for the next deadline.
+fn run_sleeps() {
+    let (sleeps, wakeup) = &*SLEEPS;
+    let mut pending = sleeps.lock().unwrap_or_else(PoisonError::into_inner);
+    loop {
+        let now = Instant::now();
+        let mut index = 0;
+        while index < pending.len() {
+            let (deadline, sender) = &pending[index];
+            if *deadline <= now || sender.is_canceled() {
+                let (_, sender) = pending.swap_remove(index);
+                let _ = sender.send(());
+            } else {
+                index += 1;
+            }
+        }
+        let next = pending.iter().map(|(deadline, _)| *deadline).min();
+        pending = match next {
+            Some(deadline) => {
+                wakeup
+                    .wait_timeout(pending, deadline - now)
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .0
+            }
+            None => wakeup.wait(pending).unwrap_or_else(PoisonError::into_inner),
+        };
+    }
+}
+
+/// Output of `future` if it completes within `timeout`,
+/// timed by tokio within a tokio runtime and by a thread otherwise.
+pub(crate) async fn timeout<F: Future>(future: F, timeout: Duration) -> Option<F::Output> {
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return tokio::time::timeout(timeout, future).await.ok();
+    }
+    match futures::future::select(pin!(future), sleep(timeout)).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+pub(crate) fn not_available(service_name: &str, timeout: Duration) -> Box<dyn std::error::Error> {
+    format!("service '{service_name}' not available after {timeout:?}").into()
+}
+
+/// Waits for the service of `r2r_client`, spinning the node meanwhile.
+pub(crate) fn wait_blocking<S>(
+    r2r_client: &r2r::Client<S>,
+    r2r_node: &SMutex<r2r::Node>,
+    availability: &Availability,
+    service_name: &str,
+    timeout: Duration,
+) -> crate::Result<()>
+where
+    S: 'static + r2r::WrappedServiceTypeSupport,
+{
+    if availability.is_ready() {
+        return Ok(());
+    }
+    let logger = r2r_node.lock_or_log("r2r_node").logger().to_string();
+    r2r::log_info!(&logger, "waiting for service '{}'...", service_name);
+
+    let is_available = r2r::Node::is_available(r2r_client)?;
+    let deadline = Some(Instant::now() + timeout);
+    match crate::blocking::spin_until(r2r_node, is_available, AVAILABILITY_SPIN, deadline) {
+        Some(result) => {
+            result?;
+            availability.set(true);
+            Ok(())
+        }
+        None => Err(not_available(service_name, timeout)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sleeps_complete_in_deadline_order() {
+        let started = Instant::now();
+        let (first, second) = futures::executor::block_on(async {
+            let long = sleep(Duration::from_millis(40)).map(|_| Instant::now());
+            let short = sleep(Duration::from_millis(10)).map(|_| Instant::now());
+            futures::join!(short, long)
+        });
+        assert!(first - started >= Duration::from_millis(10));
+        assert!(second - started >= Duration::from_millis(40));
+        assert!(first < second);
+    }
+
+    #[test]
+    fn timeout_gives_the_output_or_none() {
+        let ready = futures::executor::block_on(timeout(async { 1 }, Duration::from_secs(1)));
+        assert_eq!(ready, Some(1));
+        let pending = futures::future::pending::<u32>();
+        let expired = futures::executor::block_on(timeout(pending, Duration::from_millis(10)));
+        assert_eq!(expired, None);
+    }
+}
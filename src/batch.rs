@@ -0,0 +1,130 @@
+use std::future::Future;
+
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
+
+/// Errors are carried as `String` while the calls are in flight, to keep the futures `Send`.
+pub(crate) type CallResult<Res> = std::result::Result<Res, String>;
+
+/// Runs `call` for each request, at most `max_in_flight` at a time, the results being in order.
+pub(crate) async fn call_many<Req, Res, F, R>(
+    requests: Vec<Req>,
+    max_in_flight: usize,
+    call: F,
+) -> Vec<CallResult<Res>>
+where
+    F: FnMut(Req) -> R,
+    R: Future<Output = CallResult<Res>>,
+{
+    futures::stream::iter(requests)
+        .map(call)
+        .buffered(max_in_flight.max(1))
+        .collect()
+        .await
+}
+
+/// First successful result of `calls`, the other calls are dropped.
+pub(crate) async fn call_first<Res, R>(calls: impl IntoIterator<Item = R>) -> CallResult<Res>
+where
+    R: Future<Output = CallResult<Res>>,
+{
+    let mut calls: FuturesUnordered<R> = calls.into_iter().collect();
+    let mut errors = Vec::new();
+    while let Some(result) = calls.next().await {
+        match result {
+            Ok(response) => return Ok(response),
+            Err(e) => errors.push(e),
+        }
+    }
+    Err(format!("no service answered: [{}]", errors.join(", ")))
+}
+
+/// Results of all `calls`, in order.
+pub(crate) async fn call_all<Res, R>(calls: impl IntoIterator<Item = R>) -> Vec<CallResult<Res>>
+where
+    R: Future<Output = CallResult<Res>>,
+{
+    futures::future::join_all(calls).await
+}
+
+pub(crate) fn into_result<Res>(result: CallResult<Res>) -> crate::Result<Res> {
+    result.map_err(Into::into)
+}
+
+pub(crate) fn into_results<Res>(results: Vec<CallResult<Res>>) -> Vec<crate::Result<Res>> {
+    results.into_iter().map(into_result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::channel::oneshot;
+    use futures::executor::block_on;
+    use futures::future::ready;
+    use std::cell::RefCell;
+    use std::pin::pin;
+    use std::task::{Context, Poll};
+
+    #[test]
+    fn call_many_keeps_the_order_within_max_in_flight() {
+        let pending = RefCell::new(Vec::new());
+        let mut results = pin!(call_many(vec![1u32, 2, 3, 4, 5], 2, |request| {
+            let (sender, receiver) = oneshot::channel();
+            pending.borrow_mut().push((request, sender));
+            async move { receiver.await.map_err(|e| e.to_string()) }
+        }));
+
+        let mut context = Context::from_waker(futures::task::noop_waker_ref());
+        let results = loop {
+            if let Poll::Ready(results) = results.as_mut().poll(&mut context) {
+                break results;
+            }
+            let mut pending = pending.borrow_mut();
+            assert!(pending.len() <= 2);
+            // The latest call answers first.
+            let (request, sender) = pending.pop().unwrap();
+            sender.send(request * 10).unwrap();
+        };
+        assert_eq!(results, [Ok(10), Ok(20), Ok(30), Ok(40), Ok(50)]);
+    }
+
+    #[test]
+    fn call_first_skips_the_failed_calls() {
+        let calls = [ready(Err("a".to_string())), ready(Ok(2u32)), ready(Ok(3))];
+        assert_eq!(block_on(call_first(calls)), Ok(2));
+
+        let (sender, receiver) = oneshot::channel();
+        let calls: Vec<futures::future::BoxFuture<CallResult<u32>>> = vec![
+            Box::pin(async { receiver.await.map_err(|e| e.to_string()) }),
+            Box::pin(ready(Err("b".to_string()))),
+        ];
+        sender.send(1).unwrap();
+        assert_eq!(block_on(call_first(calls)), Ok(1));
+    }
+
+    #[test]
+    fn call_first_fails_when_all_calls_fail() {
+        let calls = [
+            ready(Err::<u32, _>("timeout".to_string())),
+            ready(Err("rejected".to_string())),
+        ];
+        let error = block_on(call_first(calls)).unwrap_err();
+        assert!(error.starts_with("no service answered"));
+        assert!(error.contains("timeout") && error.contains("rejected"));
+    }
+
+    #[test]
+    fn call_all_keeps_the_order() {
+        let calls = [ready(Ok(1u32)), ready(Err("b".to_string())), ready(Ok(3))];
+        assert_eq!(
+            block_on(call_all(calls)),
+            [Ok(1), Err("b".to_string()), Ok(3)]
+        );
+        assert!(
+            block_on(call_all(
+                Vec::<futures::future::Ready<CallResult<u32>>>::new()
+            ))
+            .is_empty()
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures::task::ArcWake;
+
+use crate::{MutexLockOrLog, SMutex};
+
+/// Spin period while waiting for a service,
+/// r2r only checks the availability of the services when the node spins.
+pub(crate) const AVAILABILITY_SPIN: Duration = Duration::from_millis(10);
+
+/// Spin period while waiting for a response, the spin returns as soon as the response arrives.
+pub(crate) const RESPONSE_SPIN: Duration = Duration::from_millis(100);
+
+/// Waker recording that its future can make progress.
+struct Woken(AtomicBool);
+
+impl ArcWake for Woken {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.store(true, Ordering::Release);
+    }
+}
+
+/// Kind of the local endpoints a blocking call can wait for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Endpoint {
+    Service,
+    Action,
+}
+
+thread_local! {
+    /// Services and action servers of the mono nodes of this thread, by fully qualified name and count of nodes:
+    /// their requests are only handled once the blocking call of this thread returns.
+    static LOCAL_ENDPOINTS: RefCell<HashMap<(Endpoint, String), usize>> = RefCell::new(HashMap::new());
+}
+
+pub(crate) fn register_local(endpoint: Endpoint, name: &str) {
+    LOCAL_ENDPOINTS.with_borrow_mut(|endpoints| {
+        *endpoints.entry((endpoint, name.to_string())).or_default() += 1;
+    });
+}
+
+pub(crate) fn unregister_local(endpoint: Endpoint, name: &str) {
+    LOCAL_ENDPOINTS.with_borrow_mut(|endpoints| {
+        let key = (endpoint, name.to_string());
+        if let Some(count) = endpoints.get_mut(&key) {
+            *count -= 1;
+            if *count == 0 {
+                endpoints.remove(&key);
+            }
+        }
+    });
+}
+
+/// Fails when a mono node of this thread provides the endpoint, a blocking call would never be answered.
+pub(crate) fn check_not_local(endpoint: Endpoint, name: &str) -> crate::Result<()> {
+    let key = (endpoint, name.to_string());
+    if LOCAL_ENDPOINTS.with_borrow(|endpoints| endpoints.contains_key(&key)) {
+        let (kind, alternative) = match endpoint {
+            Endpoint::Service => ("service", "call"),
+            Endpoint::Action => ("action", "send_goal"),
+        };
+        return Err(format!(
+            "{kind} '{name}' belongs to a node of this thread, \
+             it can not answer a blocking call, use {alternative} instead"
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Drives `future` on the thread spinning `r2r_node`, until `deadline` if any.
+///
+/// The future is only polled again once woken, by the spin that handled its event.
+pub(crate) fn spin_until<F: Future>(
+    r2r_node: &SMutex<r2r::Node>,
+    future: F,
+    spin: Duration,
+    deadline: Option<Instant>,
+) -> Option<F::Output> {
+    poll_until(future, spin, deadline, |spin| {
+        r2r_node.lock_or_log("r2r_node in client").spin_once(spin);
+    })
+}
+
+/// Polls `future` each time it is woken, calling `spin_once` in between.
+fn poll_until<F: Future>(
+    future: F,
+    spin: Duration,
+    deadline: Option<Instant>,
+    mut spin_once: impl FnMut(Duration),
+) -> Option<F::Output> {
+    let mut future = pin!(future);
+    let woken = Arc::new(Woken(AtomicBool::new(true)));
+    let waker = futures::task::waker(woken.clone());
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if woken.0.swap(false, Ordering::Acquire)
+            && let Poll::Ready(output) = future.as_mut().poll(&mut cx)
+        {
+            return Some(output);
+        }
+        let spin = match deadline {
+            Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) if !remaining.is_zero() => remaining.min(spin),
+                _ => return None,
+            },
+            None => spin,
+        };
+        spin_once(spin);
+    }
+}
+
+/// Drives `future` to completion on the thread spinning `r2r_node`.
+pub(crate) fn spin_on<F: Future>(
+    r2r_node: &SMutex<r2r::Node>,
+    future: F,
+    spin: Duration,
+) -> F::Output {
+    spin_until(r2r_node, future, spin, None).expect("no deadline")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::channel::oneshot;
+
+    const SPIN: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn local_services_fail_until_unregistered() {
+        let name = "/test/local_services";
+        assert!(check_not_local(Endpoint::Service, name).is_ok());
+
+        register_local(Endpoint::Service, name);
+        register_local(Endpoint::Service, name);
+        assert!(check_not_local(Endpoint::Service, name).is_err());
+
+        unregister_local(Endpoint::Service, name);
+        assert!(check_not_local(Endpoint::Service, name).is_err());
+        unregister_local(Endpoint::Service, name);
+        assert!(check_not_local(Endpoint::Service, name).is_ok());
+    }
+
+    #[test]
+    fn local_actions_are_apart_from_services() {
+        let name = "/test/local_action";
+        register_local(Endpoint::Action, name);
+        assert!(check_not_local(Endpoint::Action, name).is_err());
+        assert!(check_not_local(Endpoint::Service, name).is_ok());
+        unregister_local(Endpoint::Action, name);
+        assert!(check_not_local(Endpoint::Action, name).is_ok());
+    }
+
+    #[test]
+    fn local_services_are_per_thread() {
+        let name = "/test/other_thread";
+        register_local(Endpoint::Service, name);
+        let elsewhere =
+            std::thread::spawn(move || check_not_local(Endpoint::Service, name).is_ok());
+        assert!(elsewhere.join().unwrap());
+        unregister_local(Endpoint::Service, name);
+    }
+
+    #[test]
+    fn nested_call_completes() {
+        let (outer_sender, outer_response) = oneshot::channel::<u32>();
+        let mut outer_sender = Some(outer_sender);
+
+        // The first spin of the outer call runs a callback making its own blocking call,
+        // answered by the second spin.
+        let outer = poll_until(outer_response, SPIN, None, |_| {
+            let (inner_sender, inner_response) = oneshot::channel::<u32>();
+            let mut inner_sender = Some(inner_sender);
+            let inner = poll_until(inner_response, SPIN, None, |_| {
+                if let Some(sender) = inner_sender.take() {
+                    sender.send(1).unwrap();
+                }
+            });
+            if let Some(sender) = outer_sender.take() {
+                sender.send(inner.unwrap().unwrap() + 1).unwrap();
+            }
+        });
+        assert_eq!(outer.unwrap().unwrap(), 2);
+    }
+
+    #[test]
+    fn call_without_response_stops_at_the_deadline() {
+        let (_sender, response) = oneshot::channel::<u32>();
+        let deadline = Some(Instant::now() + Duration::from_millis(20));
+        let mut spins = 0;
+        let result = poll_until(response, SPIN, deadline, |spin| {
+            spins += 1;
+            std::thread::sleep(spin);
+        });
+        assert!(result.is_none());
+        assert!(spins > 0);
+    }
+}
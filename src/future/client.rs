@@ -6,7 +6,13 @@ where
     S: r2r::WrappedServiceTypeSupport,
 {
     Empty,
-    Defined { r2r_client: Arc<r2r::Client<S>> },
+    Defined {
+        r2r_client: Arc<r2r::Client<S>>,
+        service_name: String,
+        logger: String,
+        layers: crate::Layers,
+        availability: crate::Availability,
+    },
 }
 
 impl<S> std::default::Default for Client<S>
@@ -22,27 +28,121 @@ impl<S> Client<S>
 where
     S: r2r::WrappedServiceTypeSupport + 'static,
 {
-    pub async fn call(&self, request: S::Request) -> crate::Result<S::Response> {
+    /// Whether the service is available, as last seen by the node.
+    pub fn is_service_ready(&self) -> bool {
+        match self {
+            Client::Empty => false,
+            Client::Defined { availability, .. } => availability.is_ready(),
+        }
+    }
+
+    /// Calls `callback` with the new availability whenever the service appears or disappears.
+    pub fn on_availability_change<F>(&self, callback: F) -> crate::Result<()>
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        match self {
+            Client::Empty => Err("service not initialized".to_string().into()),
+            Client::Defined { availability, .. } => {
+                availability.on_change(callback);
+                Ok(())
+            }
+        }
+    }
+
+    /// Waits until the service is available, at most `timeout`.
+    pub async fn wait_for_service(&self, timeout: std::time::Duration) -> crate::Result<()> {
+        match self {
+            Client::Empty => Err("service not initialized".to_string().into()),
+            Client::Defined {
+                service_name,
+                logger,
+                availability,
+                ..
+            } => {
+                if availability.is_ready() {
+                    return Ok(());
+                }
+                r2r::log_info!(logger, "waiting for service '{}'...", service_name);
+                crate::availability::timeout(availability.wait(), timeout)
+                    .await
+                    .ok_or_else(|| crate::availability::not_available(service_name, timeout))
+            }
+        }
+    }
+
+    pub async fn call(&self, mut request: S::Request) -> crate::Result<S::Response> {
         match self {
             Client::Empty => Err("service not initialized".to_string().into()),
-            Client::Defined { r2r_client } => {
-                let r2r_client = r2r_client.clone();
+            Client::Defined {
+                r2r_client,
+                service_name,
+                layers,
+                ..
+            } => {
+                let context = crate::LayerContext {
+                    side: crate::LayerSide::Client,
+                    service_name,
+                };
+                let started = layers.request(&context, &mut request)?;
 
-                let service_available = r2r::Node::is_available(&*r2r_client)?;
+                let result = async {
+                    let r2r_client = r2r_client.clone();
 
-                match service_available.await {
-                    Ok(()) => match r2r_client.request(&request) {
-                        Ok(future) => match future.await {
-                            Ok(response) => {
-                                return Ok(response);
-                            }
+                    let service_available = r2r::Node::is_available(&*r2r_client)?;
+
+                    match service_available.await {
+                        Ok(()) => match r2r_client.request(&request) {
+                            Ok(future) => match future.await {
+                                Ok(response) => {
+                                    return Ok(response);
+                                }
+                                Err(e) => Err(e.into()),
+                            },
                             Err(e) => Err(e.into()),
                         },
                         Err(e) => Err(e.into()),
-                    },
-                    Err(e) => Err(e.into()),
+                    }
                 }
+                .await;
+                layers.result(&context, result, started)
             }
         }
     }
+
+    /// Calls the service for each request, at most `max_in_flight` at a time,
+    /// the results being in the order of the requests.
+    pub async fn call_many(
+        &self,
+        requests: Vec<S::Request>,
+        max_in_flight: usize,
+    ) -> Vec<crate::Result<S::Response>> {
+        let results =
+            crate::batch::call_many(requests, max_in_flight, |request| self.call_string(request))
+                .await;
+        crate::batch::into_results(results)
+    }
+
+    /// Sends `request` to all `clients`, returns the first successful response.
+    pub async fn call_first(clients: &[Self], request: S::Request) -> crate::Result<S::Response> {
+        let calls = clients
+            .iter()
+            .map(|client| client.call_string(request.clone()));
+        crate::batch::into_result(crate::batch::call_first(calls).await)
+    }
+
+    /// Sends `request` to all `clients`, returns their results in order.
+    pub async fn call_all(
+        clients: &[Self],
+        request: S::Request,
+    ) -> Vec<crate::Result<S::Response>> {
+        let calls = clients
+            .iter()
+            .map(|client| client.call_string(request.clone()));
+        crate::batch::into_results(crate::batch::call_all(calls).await)
+    }
+
+    async fn call_string(&self, request: S::Request) -> crate::batch::CallResult<S::Response> {
+        self.call(request).await.map_err(|e| e.to_string())
+    }
 }
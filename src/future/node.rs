@@ -1,17 +1,25 @@
 use std::sync::Arc;
 
 pub use crate::api::NodeAsync;
-pub use crate::{MutexCreate, MutexLockErr, MutexLockOrLog, Result, SMutex};
-use futures::{StreamExt, executor::ThreadPool, task::SpawnExt};
+pub use crate::{
+    ActionClient, ActionGoal, ClockType, DeliveryPolicy, GoalHandle, GoalOutcome, Latest, Layer,
+    LayerContext, LayerMessage, LayerSide, Loaned, LoggingLayer, MutexCreate, MutexLockErr,
+    MutexLockOrLog, Overflow, PublisherOptions, RawMessage, ReceiveInfo, Responder, Result, SMutex,
+    ServiceErrorPolicy, Stamped, Subscription, SubscriptionOptions, SubscriptionStats, SyncPolicy,
+    ThrottlePolicy, Time,
+};
+use futures::{FutureExt, StreamExt, executor::ThreadPool, task::SpawnExt};
 
 pub struct Node {
     r2r_node: SMutex<r2r::Node>,
     pool: ThreadPool,
+    layers: crate::Layers,
 }
 
 impl NodeAsync for Node {
     type Publisher<M: r2r::WrappedTypesupport> = crate::future::Publisher<M>;
     type Client<S: r2r::WrappedServiceTypeSupport> = crate::future::Client<S>;
+    type UntypedPublisher = crate::future::UntypedPublisher;
 
     //-------------------------------------------------- Create --------------------------------------------------
 
@@ -20,7 +28,11 @@ impl NodeAsync for Node {
         let r2r_node = SMutex::create(r2r::Node::create(ctx, name, namespace)?);
         let pool = ThreadPool::new()?;
         //
-        let node = Self { r2r_node, pool };
+        let node = Self {
+            r2r_node,
+            pool,
+            layers: crate::Layers::default(),
+        };
         Ok(node)
     }
 
@@ -33,14 +45,11 @@ impl NodeAsync for Node {
     //-------------------------------------------------- Now --------------------------------------------------
 
     fn now(&self) -> std::time::Duration {
-        let node = self.r2r_node.lock_or_log("r2r_node");
-        let ros_clock = node.get_ros_clock();
-        let mut clock = ros_clock.lock_or_log("clock");
-        let now = clock.get_now().unwrap_or_else(|e| {
-            eprintln!("[WARN] get_now() error: {e}");
-            std::time::Duration::from_secs(0)
-        });
-        now
+        self.now_with(crate::ClockType::Ros).since_epoch()
+    }
+
+    fn now_with(&self, clock_type: crate::ClockType) -> crate::Time {
+        crate::time::now_with(&self.r2r_node, clock_type)
     }
 
     //-------------------------------------------------- Logger --------------------------------------------------
@@ -333,6 +342,64 @@ impl NodeAsync for Node {
         Ok(Self::Publisher::Defined {
             logger,
             r2r_publisher,
+            throttle: None,
+            intra_process: None,
+        })
+    }
+
+    fn create_publisher_with_options<M>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::PublisherOptions,
+    ) -> Result<Self::Publisher<M>>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+    {
+        let logger = self.logger();
+
+        let r2r_publisher = {
+            let mut r2r_node = self.r2r_node.lock_err("r2r_node")?;
+            let r2_publisher = r2r_node.create_publisher(topic, qos_profile)?;
+            SMutex::create(r2_publisher)
+        };
+
+        let parts = crate::options::publisher_parts(
+            &self.r2r_node,
+            topic,
+            &options,
+            &r2r_publisher,
+            &logger,
+        )?;
+        if let Some(flush) = parts.flush {
+            self.pool.spawn(flush)?;
+        }
+
+        Ok(Self::Publisher::Defined {
+            logger,
+            r2r_publisher,
+            throttle: parts.throttle,
+            intra_process: parts.intra_process,
+        })
+    }
+
+    fn create_untyped_publisher(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> Result<Self::UntypedPublisher> {
+        let logger = self.logger();
+
+        let r2r_publisher = {
+            let mut r2r_node = self.r2r_node.lock_err("r2r_node")?;
+            let r2_publisher = r2r_node.create_publisher_untyped(topic, topic_type, qos_profile)?;
+            SMutex::create(r2_publisher)
+        };
+
+        Ok(Self::UntypedPublisher::Defined {
+            logger,
+            r2r_publisher,
         })
     }
 
@@ -544,213 +611,147 @@ impl NodeAsync for Node {
         Ok(())
     }
 
-    //-------------------------------------------------- Service --------------------------------------------------
-
-    fn create_service_0<S, F, R>(
+    fn create_subscription_with_options_0<M, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
     ) -> Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        F: Send + 'static,
-        F: Fn(S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
+        M: Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn(M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            let service = node.create_service::<S>(service_name, qos_profile)?;
-            service
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
-        //
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response = callback(request.message.clone()).await;
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", callback(msg))
+                })
+                .await
         })?;
-        //
         Ok(())
     }
 
-    fn create_service_1<S, T, F, R>(
+    fn create_subscription_with_options_1<M, T, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data: T,
     ) -> Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        F: Send + 'static,
-        F: Fn(T, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-        T: Clone + Send + 'static,
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T, M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            let service = node.create_service::<S>(service_name, qos_profile)?;
-            service
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
-        //
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response = callback(data.clone(), request.message.clone()).await;
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(
+                        &pool,
+                        "subscription callback",
+                        callback(data.clone(), msg),
+                    )
+                })
+                .await
         })?;
-        //
         Ok(())
     }
 
-    fn create_service_2<S, T1, T2, F, R>(
+    fn create_subscription_with_options_2<M, T1, T2, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
     ) -> Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        F: Send + 'static,
-        F: Fn(T1, T2, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            let service = node.create_service::<S>(service_name, qos_profile)?;
-            service
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
-        //
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response =
-                            callback(data_1.clone(), data_2.clone(), request.message.clone()).await;
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(
+                        &pool,
+                        "subscription callback",
+                        callback(data_1.clone(), data_2.clone(), msg),
+                    )
+                })
+                .await
         })?;
-        //
         Ok(())
     }
 
-    fn create_service_3<S, T1, T2, T3, F, R>(
+    fn create_subscription_with_options_3<M, T1, T2, T3, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
         data_3: T3,
     ) -> Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        F: Send + 'static,
-        F: Fn(T1, T2, T3, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
-        T3: Clone + Send + 'static,
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            let service = node.create_service::<S>(service_name, qos_profile)?;
-            service
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response = callback(
-                            data_1.clone(),
-                            data_2.clone(),
-                            data_3.clone(),
-                            request.message.clone(),
-                        )
-                        .await;
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(
+                        &pool,
+                        "subscription callback",
+                        callback(data_1.clone(), data_2.clone(), data_3.clone(), msg),
+                    )
+                })
+                .await
         })?;
         Ok(())
     }
 
-    fn create_service_4<S, T1, T2, T3, T4, F, R>(
+    fn create_subscription_with_options_4<M, T1, T2, T3, T4, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
@@ -758,58 +759,45 @@ impl NodeAsync for Node {
         data_4: T4,
     ) -> Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        F: Send + 'static,
-        F: Fn(T1, T2, T3, T4, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
-        T3: Clone + Send + 'static,
-        T4: Clone + Send + 'static,
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            let service = node.create_service::<S>(service_name, qos_profile)?;
-            service
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response = callback(
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(
+                        &pool,
+                        "subscription callback",
+                        callback(
                             data_1.clone(),
                             data_2.clone(),
                             data_3.clone(),
                             data_4.clone(),
-                            request.message.clone(),
-                        )
-                        .await;
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+                            msg,
+                        ),
+                    )
+                })
+                .await
         })?;
         Ok(())
     }
 
-    fn create_service_5<S, T1, T2, T3, T4, T5, F, R>(
+    fn create_subscription_with_options_5<M, T1, T2, T3, T4, T5, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
@@ -818,76 +806,976 @@ impl NodeAsync for Node {
         data_5: T5,
     ) -> Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
-        F: Send + 'static,
-        F: Fn(T1, T2, T3, T4, T5, S::Request) -> R,
-        R: Future<Output = S::Response>,
-        R: Send,
-        T1: Clone + Send + 'static,
-        T2: Clone + Send + 'static,
-        T3: Clone + Send + 'static,
-        T4: Clone + Send + 'static,
-        T5: Clone + Send + 'static,
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        T5: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, T5, M) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            let service = node.create_service::<S>(service_name, qos_profile)?;
-            service
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response = callback(
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(
+                        &pool,
+                        "subscription callback",
+                        callback(
                             data_1.clone(),
                             data_2.clone(),
                             data_3.clone(),
                             data_4.clone(),
                             data_5.clone(),
-                            request.message.clone(),
-                        )
-                        .await;
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+                            msg,
+                        ),
+                    )
+                })
+                .await
         })?;
         Ok(())
     }
 
-    //-------------------------------------------------- Client --------------------------------------------------
+    fn create_subscription_shared_0<M, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+    ) -> Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn(Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
+    {
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-    fn create_client<S>(
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", callback(msg))
+                })
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_shared_1<M, T, F, R>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
-    ) -> Result<Self::Client<S>>
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data: T,
+    ) -> Result<()>
     where
-        S: 'static + r2r::WrappedServiceTypeSupport,
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T, Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
     {
-        let r2r_client = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            let r2r_client = node.create_client::<S>(service_name, qos_profile)?;
-            r2r_client
-        };
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let client = Self::Client::Defined {
-            r2r_client: Arc::new(r2r_client),
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(
+                        &pool,
+                        "subscription callback",
+                        callback(data.clone(), msg),
+                    )
+                })
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_shared_2<M, T1, T2, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
+    {
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
+
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(
+                        &pool,
+                        "subscription callback",
+                        callback(data_1.clone(), data_2.clone(), msg),
+                    )
+                })
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_shared_3<M, T1, T2, T3, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
+    {
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
+
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(
+                        &pool,
+                        "subscription callback",
+                        callback(data_1.clone(), data_2.clone(), data_3.clone(), msg),
+                    )
+                })
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_shared_4<M, T1, T2, T3, T4, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
+    {
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
+
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(
+                        &pool,
+                        "subscription callback",
+                        callback(
+                            data_1.clone(),
+                            data_2.clone(),
+                            data_3.clone(),
+                            data_4.clone(),
+                            msg,
+                        ),
+                    )
+                })
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_shared_5<M, T1, T2, T3, T4, T5, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        T5: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, T5, Arc<M>) -> R,
+        R: Future<Output = ()>,
+        R: Send + 'static,
+    {
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
+
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(|msg| {
+                    crate::spawn::spawn_pooled(
+                        &pool,
+                        "subscription callback",
+                        callback(
+                            data_1.clone(),
+                            data_2.clone(),
+                            data_3.clone(),
+                            data_4.clone(),
+                            data_5.clone(),
+                            msg,
+                        ),
+                    )
+                })
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_0<M, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn(M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        self.pool.spawn(async move {
+            subscription
+                .for_each(|(msg, info)| callback(msg, info))
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_1<M, T, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T, M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        self.pool.spawn(async move {
+            subscription
+                .for_each(|(msg, info)| callback(data.clone(), msg, info))
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_2<M, T1, T2, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        self.pool.spawn(async move {
+            subscription
+                .for_each(|(msg, info)| callback(data_1.clone(), data_2.clone(), msg, info))
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_3<M, T1, T2, T3, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        self.pool.spawn(async move {
+            subscription
+                .for_each(|(msg, info)| {
+                    callback(data_1.clone(), data_2.clone(), data_3.clone(), msg, info)
+                })
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_4<M, T1, T2, T3, T4, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        self.pool.spawn(async move {
+            subscription
+                .for_each(|(msg, info)| {
+                    callback(
+                        data_1.clone(),
+                        data_2.clone(),
+                        data_3.clone(),
+                        data_4.clone(),
+                        msg,
+                        info,
+                    )
+                })
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_5<M, T1, T2, T3, T4, T5, F, R>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        T5: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static,
+        F: Fn(T1, T2, T3, T4, T5, M, crate::ReceiveInfo) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        self.pool.spawn(async move {
+            subscription
+                .for_each(|(msg, info)| {
+                    callback(
+                        data_1.clone(),
+                        data_2.clone(),
+                        data_3.clone(),
+                        data_4.clone(),
+                        data_5.clone(),
+                        msg,
+                        info,
+                    )
+                })
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn create_untyped_subscription<F, R>(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> Result<()>
+    where
+        F: Send + Sync + 'static,
+        F: Fn(serde_json::Value) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let subscription = crate::untyped::subscribe_untyped(
+            &self.r2r_node,
+            topic,
+            topic_type,
+            qos_profile,
+            self.logger(),
+        )?;
+
+        self.pool
+            .spawn(async move { subscription.for_each(callback).await })?;
+        Ok(())
+    }
+
+    fn create_raw_subscription<F, R>(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> Result<()>
+    where
+        F: Send + Sync + 'static,
+        F: Fn(RawMessage) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let subscription =
+            crate::untyped::subscribe_raw(&self.r2r_node, topic, topic_type, qos_profile)?;
+
+        self.pool
+            .spawn(async move { subscription.for_each(callback).await })?;
+        Ok(())
+    }
+
+    //-------------------------------------------------- Stream --------------------------------------------------
+
+    fn subscribe_stream<M>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> Result<crate::Subscription<M>>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+    {
+        crate::Subscription::create(&self.r2r_node, topic, qos_profile)
+    }
+
+    //-------------------------------------------------- Synchronizer --------------------------------------------------
+
+    fn create_synchronizer_2<M1, M2, F, R>(
+        &self,
+        topics: [&str; 2],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn((M1, M2)) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let synchronized = crate::synchronizer::synchronize_2::<M1, M2>(
+            &self.r2r_node,
+            topics,
+            qos_profile,
+            policy,
+        )?;
+
+        self.pool
+            .spawn(async move { synchronized.for_each(callback).await })?;
+        Ok(())
+    }
+
+    fn create_synchronizer_3<M1, M2, M3, F, R>(
+        &self,
+        topics: [&str; 3],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn((M1, M2, M3)) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let synchronized = crate::synchronizer::synchronize_3::<M1, M2, M3>(
+            &self.r2r_node,
+            topics,
+            qos_profile,
+            policy,
+        )?;
+
+        self.pool
+            .spawn(async move { synchronized.for_each(callback).await })?;
+        Ok(())
+    }
+
+    fn create_synchronizer_4<M1, M2, M3, M4, F, R>(
+        &self,
+        topics: [&str; 4],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M4: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn((M1, M2, M3, M4)) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let synchronized = crate::synchronizer::synchronize_4::<M1, M2, M3, M4>(
+            &self.r2r_node,
+            topics,
+            qos_profile,
+            policy,
+        )?;
+
+        self.pool
+            .spawn(async move { synchronized.for_each(callback).await })?;
+        Ok(())
+    }
+
+    fn create_synchronizer_5<M1, M2, M3, M4, M5, F, R>(
+        &self,
+        topics: [&str; 5],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M4: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M5: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static,
+        F: Fn((M1, M2, M3, M4, M5)) -> R,
+        R: Future<Output = ()>,
+        R: Send,
+    {
+        let synchronized = crate::synchronizer::synchronize_5::<M1, M2, M3, M4, M5>(
+            &self.r2r_node,
+            topics,
+            qos_profile,
+            policy,
+        )?;
+
+        self.pool
+            .spawn(async move { synchronized.for_each(callback).await })?;
+        Ok(())
+    }
+
+    //-------------------------------------------------- Latest --------------------------------------------------
+
+    fn create_latest<M>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> Result<crate::Latest<M>>
+    where
+        M: Clone + Send + Sync + 'static + r2r::WrappedTypesupport,
+    {
+        let (latest, feed) = crate::latest::create_latest::<M>(&self.r2r_node, topic, qos_profile)?;
+        self.pool.spawn(feed)?;
+        Ok(latest)
+    }
+
+    //-------------------------------------------------- Service --------------------------------------------------
+
+    fn add_layer<L: crate::Layer + 'static>(&mut self, layer: L) {
+        self.layers.push(layer);
+    }
+
+    fn create_service_0<S, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+    {
+        self.create_service_try_0::<S, _, _, std::convert::Infallible>(
+            service_name,
+            qos_profile,
+            crate::ServiceErrorPolicy::default(),
+            move |req| callback(req).map(Ok),
+        )
+    }
+
+    fn create_service_1<S, T, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(T, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+        T: Clone + Send + 'static,
+    {
+        self.create_service_0::<S, _, R>(service_name, qos_profile, move |req| {
+            callback(data.clone(), req)
+        })
+    }
+
+    fn create_service_2<S, T1, T2, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(T1, T2, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+    {
+        self.create_service_0::<S, _, R>(service_name, qos_profile, move |req| {
+            callback(data_1.clone(), data_2.clone(), req)
+        })
+    }
+
+    fn create_service_3<S, T1, T2, T3, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+    {
+        self.create_service_0::<S, _, R>(service_name, qos_profile, move |req| {
+            callback(data_1.clone(), data_2.clone(), data_3.clone(), req)
+        })
+    }
+
+    fn create_service_4<S, T1, T2, T3, T4, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+    {
+        self.create_service_0::<S, _, R>(service_name, qos_profile, move |req| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                data_4.clone(),
+                req,
+            )
+        })
+    }
+
+    fn create_service_5<S, T1, T2, T3, T4, T5, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(T1, T2, T3, T4, T5, S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send,
+        T1: Clone + Send + 'static,
+        T2: Clone + Send + 'static,
+        T3: Clone + Send + 'static,
+        T4: Clone + Send + 'static,
+        T5: Clone + Send + 'static,
+    {
+        self.create_service_0::<S, _, R>(service_name, qos_profile, move |req| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                data_4.clone(),
+                data_5.clone(),
+                req,
+            )
+        })
+    }
+
+    fn create_service_try_0<S, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+    ) -> Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(S::Request) -> R,
+        R: Future<Output = std::result::Result<S::Response, E>>,
+        R: Send,
+        E: std::fmt::Display,
+    {
+        let mut service_stream = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            node.create_service::<S>(service_name, qos_profile)?
+        };
+
+        let service = crate::service::ServiceContext::<S>::new(
+            self.r2r_node.clone(),
+            service_name,
+            policy,
+            self.layers.clone(),
+        );
+        self.pool.spawn(async move {
+            while let Some(request) = service_stream.next().await {
+                let called = service.call(&request, &callback);
+                service.clone().finish(request, called).await;
+            }
+        })?;
+        Ok(())
+    }
+
+    fn create_service_concurrent_0<S, F, R>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        max_concurrent: usize,
+        callback: F,
+    ) -> Result<()>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+        F: Send + 'static,
+        F: Fn(S::Request) -> R,
+        R: Future<Output = S::Response>,
+        R: Send + 'static,
+    {
+        let service_stream = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            node.create_service::<S>(service_name, qos_profile)?
+        };
+
+        let service = crate::service::ServiceContext::<S>::new(
+            self.r2r_node.clone(),
+            service_name,
+            crate::ServiceErrorPolicy::default(),
+            self.layers.clone(),
+        );
+        let pool = self.pool.clone();
+        self.pool.spawn(service_stream.for_each_concurrent(
+            max_concurrent.max(1),
+            move |request| {
+                let called = service.call(&request, |message| {
+                    callback(message).map(Ok::<_, std::convert::Infallible>)
+                });
+                crate::spawn::spawn_pooled(
+                    &pool,
+                    "service request",
+                    service.clone().finish(request, called),
+                )
+            },
+        ))?;
+        Ok(())
+    }
+
+    //-------------------------------------------------- Client --------------------------------------------------
+
+    fn create_client<S>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> Result<Self::Client<S>>
+    where
+        S: 'static + r2r::WrappedServiceTypeSupport,
+    {
+        let r2r_client = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            Arc::new(node.create_client::<S>(service_name, qos_profile)?)
+        };
+
+        let (availability, watcher) = crate::Availability::new();
+        self.pool.spawn(crate::availability::watch(
+            Arc::downgrade(&r2r_client),
+            self.r2r_node.clone(),
+            watcher,
+        ))?;
+
+        Ok(Self::Client::Defined {
+            r2r_client,
+            service_name: service_name.to_string(),
+            logger: self.logger(),
+            layers: self.layers.clone(),
+            availability,
+        })
+    }
+
+    //-------------------------------------------------- Action --------------------------------------------------
+
+    fn create_action_server_0<A, G, C, F, R>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+    ) -> Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        G: Send + 'static,
+        G: Fn(&A::Goal) -> bool,
+        C: Send + Sync + 'static,
+        C: Fn(&A::Goal) -> bool,
+        F: Send + 'static,
+        F: Fn(crate::ActionGoal<A>) -> R,
+        R: Future<Output = crate::GoalOutcome<A::Result>>,
+        R: Send + 'static,
+    {
+        let requests = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            node.create_action_server::<A>(action_name)?
+        };
+        let pool = self.pool.clone();
+        let server = crate::action::serve(
+            requests,
+            self.logger(),
+            goal_callback,
+            cancel_callback,
+            move |goal| callback(goal).map(Some),
+            move |goal| crate::spawn::spawn_pooled(&pool, "action goal", goal),
+        );
+        self.pool.spawn(server)?;
+        Ok(())
+    }
+
+    fn create_action_client<A>(&self, action_name: &str) -> Result<crate::ActionClient<A>>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        A::Feedback: Send,
+    {
+        let r2r_client = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            node.create_action_client::<A>(action_name)?
         };
-        Ok(client)
+        Ok(crate::ActionClient::new(r2r_client, action_name))
     }
 
     //-------------------------------------------------- Spin --------------------------------------------------
@@ -1,4 +1,4 @@
 pub mod node;
 pub use node::*;
 
-pub use crate::future::{Client, FMutex, Publisher};
+pub use crate::future::{Client, FMutex, Publisher, UntypedPublisher};
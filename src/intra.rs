@@ -0,0 +1,196 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Weak};
+
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+
+use crate::mailbox::{Mailbox, MailboxStream};
+use crate::{MutexCreate, MutexLockOrLog, Overflow, SMutex};
+
+/// In-process subscriptions, by fully qualified topic name.
+static CHANNELS: LazyLock<SMutex<HashMap<String, Box<dyn Any + Send>>>> =
+    LazyLock::new(|| SMutex::create(HashMap::new()));
+
+/// Number of in-process publishers, by fully qualified topic name.
+static PUBLISHERS: LazyLock<SMutex<HashMap<String, usize>>> =
+    LazyLock::new(|| SMutex::create(HashMap::new()));
+
+struct Channel<M> {
+    subscribers: Vec<Weak<Queue<M>>>,
+}
+
+/// Messages delivered to one in-process subscription.
+type Queue<M> = Mailbox<Arc<M>>;
+
+/// Subscribes an owned stream to the in-process publishers, see `SubscriptionOptions::intra_process`.
+pub(crate) type SubscribeOwned<M> = fn(&str, usize, BoxStream<'static, M>) -> BoxStream<'static, M>;
+
+/// Messages of an in-process subscription of `topic`: those of the in-process publishers,
+/// within `depth`, and the `dds` ones while `topic` has no in-process publisher,
+/// which also publishes its messages to DDS.
+pub(crate) fn subscribe<M, S>(topic: &str, depth: usize, dds: S) -> BoxStream<'static, Arc<M>>
+where
+    M: Send + Sync + 'static,
+    S: Stream<Item = M> + Send + 'static,
+{
+    let intra = register::<M>(topic, depth);
+    let topic = topic.to_string();
+    let dds = dds
+        .filter(move |_| futures::future::ready(!has_publisher(&topic)))
+        .map(Arc::new);
+    futures::stream::select(dds, intra).boxed()
+}
+
+/// Same as `subscribe`, a message is only cloned when another subscription still shares it.
+pub(crate) fn subscribe_owned<M>(
+    topic: &str,
+    depth: usize,
+    dds: BoxStream<'static, M>,
+) -> BoxStream<'static, M>
+where
+    M: Clone + Send + Sync + 'static,
+{
+    subscribe(topic, depth, dds)
+        .map(Arc::unwrap_or_clone)
+        .boxed()
+}
+
+fn register<M>(topic: &str, depth: usize) -> BoxStream<'static, Arc<M>>
+where
+    M: Send + Sync + 'static,
+{
+    let queue = Arc::new(Mailbox::new(depth.max(1), Overflow::DropOldest));
+    {
+        let mut channels = CHANNELS.lock_or_log("intra_process");
+        let channel = channels.entry(topic.to_string()).or_insert_with(|| {
+            Box::new(Channel::<M> {
+                subscribers: Vec::new(),
+            })
+        });
+        match channel.downcast_mut::<Channel<M>>() {
+            Some(channel) => channel.subscribers.push(Arc::downgrade(&queue)),
+            None => r2r::log_error!("", "intra-process topic '{}' has another type", topic),
+        }
+    }
+
+    MailboxStream(queue).boxed()
+}
+
+/// Hands the message made by `msg` to the in-process subscriptions of `topic`, if any.
+pub(crate) fn deliver<M: 'static>(topic: &str, msg: impl FnOnce() -> M) {
+    let mut channels = CHANNELS.lock_or_log("intra_process");
+    let Some(channel) = channels.get_mut(topic) else {
+        return;
+    };
+    let Some(channel) = channel.downcast_mut::<Channel<M>>() else {
+        r2r::log_error!("", "intra-process topic '{}' has another type", topic);
+        return;
+    };
+    channel.subscribers.retain(|queue| queue.strong_count() > 0);
+    if channel.subscribers.is_empty() {
+        return;
+    }
+
+    let msg = Arc::new(msg());
+    for queue in channel.subscribers.iter().filter_map(Weak::upgrade) {
+        queue.push(msg.clone());
+    }
+}
+
+fn has_publisher(topic: &str) -> bool {
+    PUBLISHERS.lock_or_log("intra_process").contains_key(topic)
+}
+
+/// In-process publisher of a topic, counted while it lives, see `PublisherOptions::intra_process`.
+pub struct IntraPublisher {
+    topic: String,
+}
+
+impl IntraPublisher {
+    pub(crate) fn new(topic: String) -> Self {
+        *PUBLISHERS
+            .lock_or_log("intra_process")
+            .entry(topic.clone())
+            .or_default() += 1;
+        Self { topic }
+    }
+
+    pub(crate) fn deliver<M: 'static>(&self, msg: impl FnOnce() -> M) {
+        deliver(&self.topic, msg);
+    }
+}
+
+impl Drop for IntraPublisher {
+    fn drop(&mut self) {
+        let mut publishers = PUBLISHERS.lock_or_log("intra_process");
+        if let Some(count) = publishers.get_mut(&self.topic) {
+            *count -= 1;
+            if *count == 0 {
+                publishers.remove(&self.topic);
+            }
+        }
+    }
+}
+
+/// Fully qualified name of `topic` for a node in `namespace`.
+pub(crate) fn topic_name(namespace: &str, topic: &str) -> String {
+    if topic.starts_with('/') {
+        topic.to_string()
+    } else {
+        format!("{}/{}", namespace.trim_end_matches('/'), topic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    /// Messages ready on `stream`, in order.
+    fn ready<M>(stream: &mut BoxStream<'static, M>) -> Vec<M> {
+        std::iter::from_fn(|| stream.next().now_or_never().flatten()).collect()
+    }
+
+    #[test]
+    fn subscriptions_share_the_published_message() {
+        let topic = "/test/shared";
+        let publisher = IntraPublisher::new(topic.to_string());
+        let mut first = subscribe::<u32, _>(topic, 10, futures::stream::pending());
+        let mut second = subscribe::<u32, _>(topic, 10, futures::stream::pending());
+
+        publisher.deliver(|| 1u32);
+        publisher.deliver(|| 2u32);
+        let first = ready(&mut first);
+        let second = ready(&mut second);
+        assert_eq!(first.iter().map(|msg| **msg).collect::<Vec<_>>(), [1, 2]);
+        assert!(Arc::ptr_eq(&first[0], &second[0]));
+    }
+
+    #[test]
+    fn depth_keeps_the_latest_messages() {
+        let topic = "/test/depth";
+        let publisher = IntraPublisher::new(topic.to_string());
+        let mut subscription = subscribe_owned::<u32>(topic, 2, futures::stream::pending().boxed());
+
+        for msg in 1..=4u32 {
+            publisher.deliver(|| msg);
+        }
+        assert_eq!(ready(&mut subscription), [3, 4]);
+    }
+
+    #[test]
+    fn dds_copies_are_skipped_while_an_intra_publisher_lives() {
+        let topic = "/test/dds";
+        let publisher = IntraPublisher::new(topic.to_string());
+        let mut subscription =
+            subscribe_owned::<u32>(topic, 10, futures::stream::iter([1, 2]).boxed());
+        publisher.deliver(|| 1u32);
+        assert_eq!(ready(&mut subscription), [1]);
+
+        drop(publisher);
+        let mut subscription =
+            subscribe_owned::<u32>(topic, 10, futures::stream::iter([1, 2]).boxed());
+        assert_eq!(ready(&mut subscription), [1, 2]);
+    }
+}
@@ -0,0 +1,63 @@
+use std::time::{Duration, Instant};
+
+use futures::{Future, StreamExt};
+use tokio::sync::watch;
+
+use crate::{MutexLockErr, SMutex};
+
+/// Most recent message of a topic, cheap to clone and shareable between callbacks.
+pub struct Latest<M> {
+    receiver: watch::Receiver<Option<(M, Instant)>>,
+}
+
+impl<M> Clone for Latest<M> {
+    fn clone(&self) -> Self {
+        Self {
+            receiver: self.receiver.clone(),
+        }
+    }
+}
+
+impl<M: Clone> Latest<M> {
+    /// Last received message, if any.
+    pub fn get(&self) -> Option<M> {
+        self.receiver.borrow().as_ref().map(|(msg, _)| msg.clone())
+    }
+
+    /// Time elapsed since the last message was received.
+    pub fn age(&self) -> Option<Duration> {
+        self.receiver
+            .borrow()
+            .as_ref()
+            .map(|(_, received)| received.elapsed())
+    }
+
+    /// Waits for the next message, returns `None` once the subscription is closed.
+    pub async fn next(&self) -> Option<M> {
+        let mut receiver = self.receiver.clone();
+        receiver.mark_unchanged();
+        receiver.changed().await.ok()?;
+        receiver.borrow().as_ref().map(|(msg, _)| msg.clone())
+    }
+}
+
+/// Creates the handle and the task feeding it, to be spawned by the node.
+pub(crate) fn create_latest<M>(
+    r2r_node: &SMutex<r2r::Node>,
+    topic: &str,
+    qos_profile: r2r::QosProfile,
+) -> crate::Result<(Latest<M>, impl Future<Output = ()> + Send + 'static)>
+where
+    M: Clone + Send + Sync + 'static + r2r::WrappedTypesupport,
+{
+    let subscription = {
+        let mut node = r2r_node.lock_err("r2r_node")?;
+        node.subscribe::<M>(topic, qos_profile)?
+    };
+    let (sender, receiver) = watch::channel(None);
+    let feed = subscription.for_each(move |msg| {
+        sender.send_replace(Some((msg, Instant::now())));
+        futures::future::ready(())
+    });
+    Ok((Latest { receiver }, feed))
+}
@@ -0,0 +1,315 @@
+use std::any::Any;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Request or response seen by a layer, to be downcast to its message type.
+pub trait LayerMessage: Debug {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<'a> dyn LayerMessage + 'a {
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        LayerMessage::as_any(self).downcast_ref()
+    }
+
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        LayerMessage::as_any_mut(self).downcast_mut()
+    }
+}
+
+impl<T: Any + Debug> LayerMessage for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayerSide {
+    Service,
+    Client,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct LayerContext<'a> {
+    pub side: LayerSide,
+    pub service_name: &'a str,
+}
+
+/// Interceptor wrapping the service handlers and the client calls of a node.
+///
+/// Requests go through the layers in the order they were added, responses in the reverse order.
+pub trait Layer: Send + Sync {
+    /// Called before the handler runs or the request is sent, an error rejects the request.
+    fn on_request(
+        &self,
+        context: &LayerContext,
+        request: &mut dyn LayerMessage,
+    ) -> std::result::Result<(), String> {
+        let _ = (context, request);
+        Ok(())
+    }
+
+    /// Called with the response, `elapsed` since the request went through the layers.
+    fn on_response(
+        &self,
+        context: &LayerContext,
+        response: &mut dyn LayerMessage,
+        elapsed: Duration,
+    ) {
+        let _ = (context, response, elapsed);
+    }
+
+    /// Called when the request is rejected, the handler fails or the call fails.
+    fn on_error(&self, context: &LayerContext, error: &str) {
+        let _ = (context, error);
+    }
+}
+
+/// Logs the requests, the responses with their latency, and the errors.
+pub struct LoggingLayer {
+    logger: String,
+}
+
+impl LoggingLayer {
+    pub fn new(logger: &str) -> Self {
+        Self {
+            logger: logger.to_string(),
+        }
+    }
+}
+
+impl Layer for LoggingLayer {
+    fn on_request(
+        &self,
+        context: &LayerContext,
+        request: &mut dyn LayerMessage,
+    ) -> std::result::Result<(), String> {
+        r2r::log_info!(
+            &self.logger,
+            "{:?} '{}' request: {:?}",
+            context.side,
+            context.service_name,
+            request
+        );
+        Ok(())
+    }
+
+    fn on_response(
+        &self,
+        context: &LayerContext,
+        response: &mut dyn LayerMessage,
+        elapsed: Duration,
+    ) {
+        r2r::log_info!(
+            &self.logger,
+            "{:?} '{}' response in {:?}: {:?}",
+            context.side,
+            context.service_name,
+            elapsed,
+            response
+        );
+    }
+
+    fn on_error(&self, context: &LayerContext, error: &str) {
+        r2r::log_error!(
+            &self.logger,
+            "{:?} '{}' error: {}",
+            context.side,
+            context.service_name,
+            error
+        );
+    }
+}
+
+/// Layers of a node, cheap to clone.
+#[derive(Clone, Default)]
+pub struct Layers(Arc<Vec<Arc<dyn Layer>>>);
+
+impl Layers {
+    pub(crate) fn push<L: Layer + 'static>(&mut self, layer: L) {
+        let mut layers = self.0.as_ref().clone();
+        layers.push(Arc::new(layer));
+        self.0 = Arc::new(layers);
+    }
+
+    pub(crate) fn request<M: LayerMessage>(
+        &self,
+        context: &LayerContext,
+        request: &mut M,
+    ) -> std::result::Result<Instant, String> {
+        for layer in self.0.iter() {
+            if let Err(e) = layer.on_request(context, request) {
+                self.error(context, &e);
+                return Err(e);
+            }
+        }
+        Ok(Instant::now())
+    }
+
+    pub(crate) fn response<M: LayerMessage>(
+        &self,
+        context: &LayerContext,
+        response: &mut M,
+        started: Instant,
+    ) {
+        let elapsed = started.elapsed();
+        for layer in self.0.iter().rev() {
+            layer.on_response(context, response, elapsed);
+        }
+    }
+
+    pub(crate) fn error(&self, context: &LayerContext, error: &str) {
+        for layer in self.0.iter().rev() {
+            layer.on_error(context, error);
+        }
+    }
+
+    /// Passes the result of a client call through the layers.
+    pub(crate) fn result<M: LayerMessage>(
+        &self,
+        context: &LayerContext,
+        result: crate::Result<M>,
+        started: Instant,
+    ) -> crate::Result<M> {
+        match result {
+            Ok(mut response) => {
+                self.response(context, &mut response, started);
+                Ok(response)
+            }
+            Err(e) => {
+                self.error(context, &e.to_string());
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Mutex, PoisonError};
+
+    type Events = Arc<Mutex<Vec<String>>>;
+
+    /// Records its calls in `events`, rejects the requests when `reject` is set.
+    struct Recording {
+        name: &'static str,
+        reject: bool,
+        events: Events,
+    }
+
+    impl Layer for Recording {
+        fn on_request(
+            &self,
+            _context: &LayerContext,
+            request: &mut dyn LayerMessage,
+        ) -> std::result::Result<(), String> {
+            self.record(format!("request {:?}", request));
+            if self.reject {
+                return Err(format!("rejected by {}", self.name));
+            }
+            Ok(())
+        }
+
+        fn on_response(
+            &self,
+            _context: &LayerContext,
+            response: &mut dyn LayerMessage,
+            _elapsed: Duration,
+        ) {
+            self.record(format!("response {:?}", response));
+        }
+
+        fn on_error(&self, _context: &LayerContext, error: &str) {
+            self.record(format!("error {}", error));
+        }
+    }
+
+    impl Recording {
+        fn record(&self, event: String) {
+            self.events
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(format!("{} {}", self.name, event));
+        }
+    }
+
+    fn layers(reject_second: bool) -> (Layers, Events) {
+        let events = Events::default();
+        let mut layers = Layers::default();
+        for (name, reject) in [("first", false), ("second", reject_second)] {
+            layers.push(Recording {
+                name,
+                reject,
+                events: events.clone(),
+            });
+        }
+        (layers, events)
+    }
+
+    fn context() -> LayerContext<'static> {
+        LayerContext {
+            side: LayerSide::Client,
+            service_name: "/test",
+        }
+    }
+
+    fn take(events: &Events) -> Vec<String> {
+        std::mem::take(&mut events.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    #[test]
+    fn responses_go_through_the_layers_in_reverse_order() {
+        let (layers, events) = layers(false);
+        let started = layers.request(&context(), &mut 1u32).unwrap();
+        let response = layers.result(&context(), Ok(2u32), started).unwrap();
+        assert_eq!(response, 2);
+        assert_eq!(
+            take(&events),
+            [
+                "first request 1",
+                "second request 1",
+                "second response 2",
+                "first response 2"
+            ]
+        );
+    }
+
+    #[test]
+    fn rejected_requests_reach_on_error() {
+        let (layers, events) = layers(true);
+        let error = layers.request(&context(), &mut 1u32).unwrap_err();
+        assert_eq!(error, "rejected by second");
+        assert_eq!(
+            take(&events),
+            [
+                "first request 1",
+                "second request 1",
+                "second error rejected by second",
+                "first error rejected by second"
+            ]
+        );
+    }
+
+    #[test]
+    fn failed_calls_reach_on_error() {
+        let (layers, events) = layers(false);
+        let started = layers.request(&context(), &mut 1u32).unwrap();
+        take(&events);
+        assert!(
+            layers
+                .result::<u32>(&context(), Err("timeout".into()), started)
+                .is_err()
+        );
+        assert_eq!(
+            take(&events),
+            ["second error timeout", "first error timeout"]
+        );
+    }
+}
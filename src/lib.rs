@@ -4,8 +4,40 @@ pub mod future_mono;
 mod macros;
 pub mod multi;
 pub mod mono;
+mod action;
+pub use action::*;
+mod availability;
+pub use availability::*;
+mod batch;
+mod blocking;
+mod intra;
+mod latest;
+pub use latest::*;
+mod layer;
+pub use layer::*;
+mod loaned;
+pub use loaned::*;
+mod mailbox;
+mod options;
+pub use options::*;
+mod receive_info;
+pub use receive_info::*;
+mod service;
+pub use service::*;
+mod spawn;
+mod subscription;
+pub use subscription::*;
+mod synchronizer;
+pub use synchronizer::*;
+mod throttle;
+pub use throttle::*;
+mod time;
+pub use time::*;
+mod untyped;
+pub use untyped::*;
 pub mod tokio;
 pub mod tokio_mono;
+pub mod tools;
 
 pub type Result<T> = ::core::result::Result<T, Box<dyn std::error::Error>>;
 
@@ -0,0 +1,83 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+use crate::{MutexLockOrLog, SMutex};
+
+/// Message borrowed from the middleware, filled in place and then published without copy.
+///
+/// When the middleware can not loan messages, it is allocated locally and published as a native message.
+/// Loaned messages bypass the throttle of the publisher,
+/// an intra-process publisher converts them back to hand them to the in-process subscriptions.
+pub struct Loaned<M>
+where
+    M: r2r::WrappedTypesupport,
+{
+    msg: r2r::NativeMsg<M>,
+    r2r_publisher: SMutex<r2r::Publisher<M>>,
+    intra_process: Option<Arc<crate::intra::IntraPublisher>>,
+    logger: String,
+}
+
+impl<M> Loaned<M>
+where
+    M: r2r::WrappedTypesupport + 'static,
+{
+    pub(crate) fn borrow(
+        r2r_publisher: &SMutex<r2r::Publisher<M>>,
+        intra_process: Option<Arc<crate::intra::IntraPublisher>>,
+        logger: &str,
+    ) -> crate::Result<Self> {
+        let msg = r2r_publisher
+            .lock_or_log("r2r_publisher")
+            .borrow_loaned_message()?;
+        Ok(Self {
+            msg,
+            r2r_publisher: r2r_publisher.clone(),
+            intra_process,
+            logger: logger.to_string(),
+        })
+    }
+
+    /// `false` when the middleware could not loan the message.
+    pub fn is_loaned(&self) -> bool {
+        self.msg.is_loaned
+    }
+
+    /// Fills the message from its Rust representation.
+    pub fn copy_from(&mut self, msg: &M) {
+        msg.copy_to_native(&mut self.msg);
+    }
+
+    pub fn publish(mut self) {
+        if let Some(intra_process) = &self.intra_process {
+            intra_process.deliver(|| M::from_native(&self.msg));
+        }
+        let result = self
+            .r2r_publisher
+            .lock_or_log("r2r_publisher")
+            .publish_native(&mut self.msg);
+        if let Err(e) = result {
+            r2r::log_error!(&self.logger, "{}", e);
+        }
+    }
+}
+
+impl<M> Deref for Loaned<M>
+where
+    M: r2r::WrappedTypesupport,
+{
+    type Target = M::CStruct;
+
+    fn deref(&self) -> &Self::Target {
+        &self.msg
+    }
+}
+
+impl<M> DerefMut for Loaned<M>
+where
+    M: r2r::WrappedTypesupport,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.msg
+    }
+}
@@ -92,6 +92,126 @@ macro_rules! create_subscription {
 
 #[macro_export]
 macro_rules! create_service {
+    (try $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $policy:expr, $callback:expr $(,)?) => {
+        ($node).create_service_try_0::<$service_ty, _, _, _>(
+            $service_name,
+            $qos_profile,
+            $policy,
+            $callback,
+        )
+    };
+    (try $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $policy:expr, $callback:expr, $data:expr $(,)?) => {
+        ($node).create_service_try_1::<$service_ty, _, _, _, _>(
+            $service_name,
+            $qos_profile,
+            $policy,
+            $callback,
+            $data,
+        )
+    };
+    (try $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $policy:expr, $callback:expr, $data_1:expr, $data_2:expr $(,)?) => {
+        ($node).create_service_try_2::<$service_ty, _, _, _, _, _>(
+            $service_name,
+            $qos_profile,
+            $policy,
+            $callback,
+            $data_1,
+            $data_2,
+        )
+    };
+    (try $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $policy:expr, $callback:expr, $data_1:expr, $data_2:expr, $data_3:expr $(,)?) => {
+        ($node).create_service_try_3::<$service_ty, _, _, _, _, _, _>(
+            $service_name,
+            $qos_profile,
+            $policy,
+            $callback,
+            $data_1,
+            $data_2,
+            $data_3,
+        )
+    };
+    (try $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $policy:expr, $callback:expr, $data_1:expr, $data_2:expr, $data_3:expr, $data_4:expr $(,)?) => {
+        ($node).create_service_try_4::<$service_ty, _, _, _, _, _, _, _>(
+            $service_name,
+            $qos_profile,
+            $policy,
+            $callback,
+            $data_1,
+            $data_2,
+            $data_3,
+            $data_4,
+        )
+    };
+    (try $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $policy:expr, $callback:expr, $data_1:expr, $data_2:expr, $data_3:expr, $data_4:expr, $data_5:expr $(,)?) => {
+        ($node).create_service_try_5::<$service_ty, _, _, _, _, _, _, _, _>(
+            $service_name,
+            $qos_profile,
+            $policy,
+            $callback,
+            $data_1,
+            $data_2,
+            $data_3,
+            $data_4,
+            $data_5,
+        )
+    };
+    (deferred $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $callback:expr $(,)?) => {
+        ($node).create_service_deferred_0::<$service_ty, _>(
+            $service_name,
+            $qos_profile,
+            $callback,
+        )
+    };
+    (deferred $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $callback:expr, $data:expr $(,)?) => {
+        ($node).create_service_deferred_1::<$service_ty, _, _>(
+            $service_name,
+            $qos_profile,
+            $callback,
+            $data,
+        )
+    };
+    (deferred $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $callback:expr, $data_1:expr, $data_2:expr $(,)?) => {
+        ($node).create_service_deferred_2::<$service_ty, _, _, _>(
+            $service_name,
+            $qos_profile,
+            $callback,
+            $data_1,
+            $data_2,
+        )
+    };
+    (deferred $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $callback:expr, $data_1:expr, $data_2:expr, $data_3:expr $(,)?) => {
+        ($node).create_service_deferred_3::<$service_ty, _, _, _, _>(
+            $service_name,
+            $qos_profile,
+            $callback,
+            $data_1,
+            $data_2,
+            $data_3,
+        )
+    };
+    (deferred $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $callback:expr, $data_1:expr, $data_2:expr, $data_3:expr, $data_4:expr $(,)?) => {
+        ($node).create_service_deferred_4::<$service_ty, _, _, _, _, _>(
+            $service_name,
+            $qos_profile,
+            $callback,
+            $data_1,
+            $data_2,
+            $data_3,
+            $data_4,
+        )
+    };
+    (deferred $node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $callback:expr, $data_1:expr, $data_2:expr, $data_3:expr, $data_4:expr, $data_5:expr $(,)?) => {
+        ($node).create_service_deferred_5::<$service_ty, _, _, _, _, _, _>(
+            $service_name,
+            $qos_profile,
+            $callback,
+            $data_1,
+            $data_2,
+            $data_3,
+            $data_4,
+            $data_5,
+        )
+    };
     ($node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr, $callback:expr $(,)?) => {
         ($node).create_service_typed_0::<$service_ty, _, _>(
             $service_name,
@@ -157,7 +277,148 @@ macro_rules! create_service {
              - create_service!(node, ServiceType, service_name, qos_profile, callback, data1, data2)\n\
              - create_service!(node, ServiceType, service_name, qos_profile, callback, data1, data2, data3)\n\
              - create_service!(node, ServiceType, service_name, qos_profile, callback, data1, data2, data3, data4)\n\
-             - create_service!(node, ServiceType, service_name, qos_profile, callback, data1, data2, data3, data4, data5)"
+             - create_service!(node, ServiceType, service_name, qos_profile, callback, data1, data2, data3, data4, data5)\n\
+             - create_service!(try node, ServiceType, service_name, qos_profile, policy, callback[, data1, ..., data5])\n\
+             - create_service!(deferred node, ServiceType, service_name, qos_profile, callback[, data1, ..., data5])"
         )
     };
 }
+
+#[macro_export]
+macro_rules! create_publisher {
+    ($node:expr, $msg_ty:ty, $topic:expr, $qos_profile:expr $(,)?) => {
+        ($node).create_publisher::<$msg_ty>($topic, $qos_profile)
+    };
+    ($node:expr, $msg_ty:ty, $topic:expr, $qos_profile:expr, $options:expr $(,)?) => {
+        ($node).create_publisher_with_options::<$msg_ty>($topic, $qos_profile, $options)
+    };
+    ($($tt:tt)*) => {
+        compile_error!(
+            "Invalid `create_publisher!` invocation. Expected one of:\n\
+             - create_publisher!(node, MessageType, topic, qos_profile)\n\
+             - create_publisher!(node, MessageType, topic, qos_profile, options)"
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! create_client {
+    ($node:expr, $service_ty:ty, $service_name:expr, $qos_profile:expr $(,)?) => {
+        ($node).create_client::<$service_ty>($service_name, $qos_profile)
+    };
+    ($($tt:tt)*) => {
+        compile_error!(
+            "Invalid `create_client!` invocation. Expected:\n\
+             - create_client!(node, ServiceType, service_name, qos_profile)"
+        )
+    };
+}
+
+/// The action type annotates the callbacks, the arity methods having no common turbofish.
+#[macro_export]
+macro_rules! create_action_server {
+    ($node:expr, $action_ty:ty, $action_name:expr, $goal_callback:expr, $cancel_callback:expr, $callback:expr $(,)?) => {{
+        let callback = $callback;
+        ($node).create_action_server_0(
+            $action_name,
+            $crate::action_goal_callback::<$action_ty, _>($goal_callback),
+            $crate::action_goal_callback::<$action_ty, _>($cancel_callback),
+            move |goal: $crate::ActionGoal<$action_ty>| callback(goal),
+        )
+    }};
+    ($node:expr, $action_ty:ty, $action_name:expr, $goal_callback:expr, $cancel_callback:expr, $callback:expr, $data:expr $(,)?) => {{
+        let callback = $callback;
+        ($node).create_action_server_1(
+            $action_name,
+            $crate::action_goal_callback::<$action_ty, _>($goal_callback),
+            $crate::action_goal_callback::<$action_ty, _>($cancel_callback),
+            move |data, goal: $crate::ActionGoal<$action_ty>| callback(data, goal),
+            $data,
+        )
+    }};
+    ($node:expr, $action_ty:ty, $action_name:expr, $goal_callback:expr, $cancel_callback:expr, $callback:expr, $data_1:expr, $data_2:expr $(,)?) => {{
+        let callback = $callback;
+        ($node).create_action_server_2(
+            $action_name,
+            $crate::action_goal_callback::<$action_ty, _>($goal_callback),
+            $crate::action_goal_callback::<$action_ty, _>($cancel_callback),
+            move |data_1, data_2, goal: $crate::ActionGoal<$action_ty>| callback(data_1, data_2, goal),
+            $data_1,
+            $data_2,
+        )
+    }};
+    ($node:expr, $action_ty:ty, $action_name:expr, $goal_callback:expr, $cancel_callback:expr, $callback:expr, $data_1:expr, $data_2:expr, $data_3:expr $(,)?) => {{
+        let callback = $callback;
+        ($node).create_action_server_3(
+            $action_name,
+            $crate::action_goal_callback::<$action_ty, _>($goal_callback),
+            $crate::action_goal_callback::<$action_ty, _>($cancel_callback),
+            move |data_1, data_2, data_3, goal: $crate::ActionGoal<$action_ty>| callback(data_1, data_2, data_3, goal),
+            $data_1,
+            $data_2,
+            $data_3,
+        )
+    }};
+    ($node:expr, $action_ty:ty, $action_name:expr, $goal_callback:expr, $cancel_callback:expr, $callback:expr, $data_1:expr, $data_2:expr, $data_3:expr, $data_4:expr $(,)?) => {{
+        let callback = $callback;
+        ($node).create_action_server_4(
+            $action_name,
+            $crate::action_goal_callback::<$action_ty, _>($goal_callback),
+            $crate::action_goal_callback::<$action_ty, _>($cancel_callback),
+            move |data_1, data_2, data_3, data_4, goal: $crate::ActionGoal<$action_ty>| callback(data_1, data_2, data_3, data_4, goal),
+            $data_1,
+            $data_2,
+            $data_3,
+            $data_4,
+        )
+    }};
+    ($node:expr, $action_ty:ty, $action_name:expr, $goal_callback:expr, $cancel_callback:expr, $callback:expr, $data_1:expr, $data_2:expr, $data_3:expr, $data_4:expr, $data_5:expr $(,)?) => {{
+        let callback = $callback;
+        ($node).create_action_server_5(
+            $action_name,
+            $crate::action_goal_callback::<$action_ty, _>($goal_callback),
+            $crate::action_goal_callback::<$action_ty, _>($cancel_callback),
+            move |data_1, data_2, data_3, data_4, data_5, goal: $crate::ActionGoal<$action_ty>| callback(data_1, data_2, data_3, data_4, data_5, goal),
+            $data_1,
+            $data_2,
+            $data_3,
+            $data_4,
+            $data_5,
+        )
+    }};
+    ($($tt:tt)*) => {
+        compile_error!(
+            "Invalid `create_action_server!` invocation. Expected one of:\n\
+             - create_action_server!(node, ActionType, action_name, goal_callback, cancel_callback, callback)\n\
+             - create_action_server!(node, ActionType, action_name, goal_callback, cancel_callback, callback, data)\n\
+             - create_action_server!(node, ActionType, action_name, goal_callback, cancel_callback, callback, data1, ..., data5)"
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! create_action_client {
+    ($node:expr, $action_ty:ty, $action_name:expr $(,)?) => {
+        ($node).create_action_client::<$action_ty>($action_name)
+    };
+    ($($tt:tt)*) => {
+        compile_error!(
+            "Invalid `create_action_client!` invocation. Expected:\n\
+             - create_action_client!(node, ActionType, action_name)"
+        )
+    };
+}
+
+/// Implements `Stamped` for messages with a `header: std_msgs/Header` field.
+#[macro_export]
+macro_rules! impl_stamped {
+    ($($msg:ty),* $(,)?) => {
+        $(
+            impl $crate::Stamped for $msg {
+                fn stamp(&self) -> &r2r::builtin_interfaces::msg::Time {
+                    &self.header.stamp
+                }
+            }
+        )*
+    };
+}
@@ -0,0 +1,117 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
+
+use futures::task::AtomicWaker;
+
+use crate::{MutexCreate, MutexLockOrLog, Overflow, SMutex};
+
+/// Bounded queue between a subscription and its callback.
+pub(crate) struct Mailbox<M> {
+    queue: SMutex<VecDeque<M>>,
+    capacity: usize,
+    overflow: Overflow,
+    waker: AtomicWaker,
+    closed: AtomicBool,
+}
+
+impl<M> Mailbox<M> {
+    pub fn new(capacity: usize, overflow: Overflow) -> Self {
+        Self {
+            queue: SMutex::create(VecDeque::with_capacity(capacity)),
+            capacity: capacity.max(1),
+            overflow,
+            waker: AtomicWaker::new(),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns `false` when a message had to be dropped.
+    pub fn push(&self, msg: M) -> bool {
+        let accepted = {
+            let mut queue = self.queue.lock_or_log("mailbox");
+            if queue.len() < self.capacity {
+                queue.push_back(msg);
+                true
+            } else {
+                if self.overflow == Overflow::DropOldest {
+                    queue.pop_front();
+                    queue.push_back(msg);
+                }
+                false
+            }
+        };
+        self.waker.wake();
+        accepted
+    }
+
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.waker.wake();
+    }
+}
+
+pub(crate) struct MailboxStream<M>(pub Arc<Mailbox<M>>);
+
+impl<M> futures::Stream for MailboxStream<M> {
+    type Item = M;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<M>> {
+        let mailbox = &self.0;
+        mailbox.waker.register(cx.waker());
+        // read before popping: every message pushed before `close` is then visible
+        let closed = mailbox.closed.load(Ordering::Acquire);
+        if let Some(msg) = mailbox.queue.lock_or_log("mailbox").pop_front() {
+            return Poll::Ready(Some(msg));
+        }
+        if closed {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{FutureExt, StreamExt};
+
+    fn filled(overflow: Overflow) -> (Arc<Mailbox<u32>>, Vec<bool>) {
+        let mailbox = Arc::new(Mailbox::new(2, overflow));
+        let accepted = (1..=4).map(|msg| mailbox.push(msg)).collect();
+        (mailbox, accepted)
+    }
+
+    fn drain(mailbox: Arc<Mailbox<u32>>) -> Vec<u32> {
+        mailbox.close();
+        MailboxStream(mailbox)
+            .collect()
+            .now_or_never()
+            .expect("closed")
+    }
+
+    #[test]
+    fn drop_oldest_keeps_the_newest_messages() {
+        let (mailbox, accepted) = filled(Overflow::DropOldest);
+        assert_eq!(accepted, [true, true, false, false]);
+        assert_eq!(drain(mailbox), [3, 4]);
+    }
+
+    #[test]
+    fn drop_newest_keeps_the_oldest_messages() {
+        let (mailbox, accepted) = filled(Overflow::DropNewest);
+        assert_eq!(accepted, [true, true, false, false]);
+        assert_eq!(drain(mailbox), [1, 2]);
+    }
+
+    #[test]
+    fn closed_mailbox_delivers_its_messages_first() {
+        let mailbox = Arc::new(Mailbox::new(4, Overflow::DropOldest));
+        mailbox.push(1);
+        mailbox.push(2);
+        assert_eq!(drain(mailbox), [1, 2]);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::MutexLockErr;
+use futures::task::LocalSpawnExt;
+
+/// Client of an action server, running its callbacks on the node thread.
+pub enum ActionClient<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    Empty,
+    Defined {
+        client: crate::ActionClient<A>,
+        r2r_node: crate::SMutex<r2r::Node>,
+        local_spawner: futures::executor::LocalSpawner,
+    },
+}
+
+impl<A> Default for ActionClient<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    fn default() -> Self {
+        Self::Empty
+    }
+}
+
+impl<A> Clone for ActionClient<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Empty => Self::Empty,
+            Self::Defined {
+                client,
+                r2r_node,
+                local_spawner,
+            } => Self::Defined {
+                client: client.clone(),
+                r2r_node: r2r_node.clone(),
+                local_spawner: local_spawner.clone(),
+            },
+        }
+    }
+}
+
+impl<A> ActionClient<A>
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+    A::Result: Send,
+    A::Feedback: Send,
+{
+    /// Waits until the action server is available, at most `timeout`, spinning the node meanwhile.
+    pub fn wait_for_server(&self, timeout: std::time::Duration) -> crate::Result<()> {
+        match self {
+            Self::Empty => Err("action client not initialized".into()),
+            Self::Defined {
+                client, r2r_node, ..
+            } => {
+                let spin = crate::blocking::AVAILABILITY_SPIN;
+                crate::blocking::spin_on(r2r_node, client.wait_for_server(timeout), spin)
+            }
+        }
+    }
+
+    /// Sends `goal`, `feedback_callback` is called with each feedback of the goal
+    /// and `result_callback` with its final status and result, or the rejection of the goal.
+    pub fn send_goal<FF, RF>(
+        &self,
+        goal: A::Goal,
+        feedback_callback: FF,
+        result_callback: RF,
+    ) -> crate::Result<()>
+    where
+        FF: FnMut(A::Feedback) + 'static,
+        RF: FnOnce(crate::Result<(r2r::GoalStatus, A::Result)>) + 'static,
+    {
+        match self {
+            Self::Empty => Err("action client not initialized".into()),
+            Self::Defined {
+                client,
+                local_spawner,
+                ..
+            } => {
+                let client = client.clone();
+                local_spawner.spawn_local(async move {
+                    let result = crate::action::follow_goal(client, goal, feedback_callback).await;
+                    result_callback(result.map_err(|e| e.into()));
+                })?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Sends `goal` and returns its final status and result, spinning the node meanwhile.
+    ///
+    /// Called from a callback, the nested spin handles all the events of the node and can lose messages,
+    /// as for `Client::call_blocking`. The action servers of the mono nodes of this thread can not execute
+    /// the goal meanwhile, sending one to them returns an error, use `send_goal` instead.
+    pub fn send_goal_blocking<FF>(
+        &self,
+        goal: A::Goal,
+        feedback_callback: FF,
+    ) -> crate::Result<(r2r::GoalStatus, A::Result)>
+    where
+        FF: FnMut(A::Feedback),
+    {
+        self.send_goal_blocking_until(goal, None, feedback_callback)
+    }
+
+    /// As `send_goal_blocking`, failing when the goal has not ended within `timeout`.
+    pub fn send_goal_blocking_timeout<FF>(
+        &self,
+        goal: A::Goal,
+        timeout: std::time::Duration,
+        feedback_callback: FF,
+    ) -> crate::Result<(r2r::GoalStatus, A::Result)>
+    where
+        FF: FnMut(A::Feedback),
+    {
+        self.send_goal_blocking_until(goal, Some(timeout), feedback_callback)
+    }
+
+    fn send_goal_blocking_until<FF>(
+        &self,
+        goal: A::Goal,
+        timeout: Option<std::time::Duration>,
+        feedback_callback: FF,
+    ) -> crate::Result<(r2r::GoalStatus, A::Result)>
+    where
+        FF: FnMut(A::Feedback),
+    {
+        match self {
+            Self::Empty => Err("action client not initialized".into()),
+            Self::Defined {
+                client, r2r_node, ..
+            } => {
+                let crate::ActionClient::Defined { action_name, .. } = client else {
+                    return Err("action client not initialized".into());
+                };
+                let namespace = r2r_node.lock_err("r2r_node")?.namespace()?;
+                let name = crate::intra::topic_name(&namespace, action_name);
+                crate::blocking::check_not_local(crate::blocking::Endpoint::Action, &name)?;
+
+                let deadline = timeout.map(|timeout| std::time::Instant::now() + timeout);
+                let follow = crate::action::follow_goal(client.clone(), goal, feedback_callback);
+                let spin = crate::blocking::AVAILABILITY_SPIN;
+                match crate::blocking::spin_until(r2r_node, follow, spin, deadline) {
+                    Some(result) => result.map_err(|e| e.into()),
+                    None => Err(format!(
+                        "goal of action '{name}' not ended after {:?}",
+                        timeout.unwrap_or_default()
+                    )
+                    .into()),
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use crate::MutexLockOrLog;
+use crate::MutexLockErr;
+use futures::TryFutureExt;
 use futures::task::LocalSpawnExt;
 use std::future::Future;
 use std::sync::Arc;
-use std::task::{Context, Poll};
 
 #[derive(Clone)]
 pub enum Client<S>
@@ -14,6 +14,9 @@ where
         r2r_client: Arc<r2r::Client<S>>,
         r2r_node: crate::SMutex<r2r::Node>,
         local_spawner: futures::executor::LocalSpawner,
+        service_name: String,
+        layers: crate::Layers,
+        availability: crate::Availability,
     },
 }
 
@@ -30,78 +33,258 @@ impl<S> Client<S>
 where
     S: r2r::WrappedServiceTypeSupport + 'static,
 {
+    /// Whether the service is available, as last seen by the node.
+    pub fn is_service_ready(&self) -> bool {
+        match self {
+            Client::Empty => false,
+            Client::Defined { availability, .. } => availability.is_ready(),
+        }
+    }
+
+    /// Calls `callback` with the new availability whenever the service appears or disappears.
+    pub fn on_availability_change<F>(&self, callback: F) -> crate::Result<()>
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        match self {
+            Client::Empty => Err("service not initialized".to_string().into()),
+            Client::Defined { availability, .. } => {
+                availability.on_change(callback);
+                Ok(())
+            }
+        }
+    }
+
+    /// Waits until the service is available, at most `timeout`, spinning the node meanwhile.
+    pub fn wait_for_service(&self, timeout: std::time::Duration) -> crate::Result<()> {
+        match self {
+            Client::Empty => Err("service not initialized".to_string().into()),
+            Client::Defined {
+                r2r_client,
+                r2r_node,
+                service_name,
+                availability,
+                ..
+            } => crate::availability::wait_blocking(
+                r2r_client,
+                r2r_node,
+                availability,
+                service_name,
+                timeout,
+            ),
+        }
+    }
+
+    /// Sends `request` and spins the node until the response arrives.
+    ///
+    /// Called from a callback, the nested spin handles all the events of the node, not only this response:
+    /// r2r queues the messages of each subscription in a channel of 10 and drops the newer ones when it is full,
+    /// so a long call can lose messages; their callbacks run once this callback returns.
+    /// The services of the mono nodes of this thread can not answer meanwhile, calling one returns an error,
+    /// use `call` instead.
     pub fn call_blocking(&self, request: S::Request) -> crate::Result<S::Response> {
+        self.call_blocking_until(request, None)
+    }
+
+    /// As `call_blocking`, failing when the service is not available or does not answer within `timeout`.
+    pub fn call_blocking_timeout(
+        &self,
+        request: S::Request,
+        timeout: std::time::Duration,
+    ) -> crate::Result<S::Response> {
+        self.call_blocking_until(request, Some(timeout))
+    }
+
+    fn call_blocking_until(
+        &self,
+        mut request: S::Request,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<S::Response> {
         match self {
             Client::Empty => Err("service not initialized".to_string().into()),
             Client::Defined {
                 r2r_client,
                 r2r_node,
+                service_name,
+                layers,
+                availability,
                 ..
             } => {
-                let r2r_client = r2r_client.clone();
-                let service_available = r2r::Node::is_available(&*r2r_client)?;
-                let mut service_available = Box::pin(service_available);
-
-                loop {
-                    let waker = futures::task::noop_waker_ref();
-                    let mut cx = Context::from_waker(waker);
-                    match service_available.as_mut().poll(&mut cx) {
-                        Poll::Ready(result) => {
-                            result?;
-                            break;
-                        }
-                        Poll::Pending => {
-                            let mut node = r2r_node.lock_or_log("r2r_node in client.call");
-                            node.spin_once(std::time::Duration::from_millis(1));
-                        }
-                    }
-                }
-
-                let response_future = r2r_client.request(&request)?;
-                let mut response_future = Box::pin(response_future);
-
-                loop {
-                    let waker = futures::task::noop_waker_ref();
-                    let mut cx = Context::from_waker(waker);
-                    match response_future.as_mut().poll(&mut cx) {
-                        Poll::Ready(result) => return result.map_err(|e| e.into()),
-                        Poll::Pending => {
-                            let mut node = r2r_node.lock_or_log("r2r_node in client.call_blocking");
-                            node.spin_once(std::time::Duration::from_millis(1));
-                        }
-                    }
-                }
+                let context = crate::LayerContext {
+                    side: crate::LayerSide::Client,
+                    service_name,
+                };
+                let started = layers.request(&context, &mut request)?;
+                let result = Self::check_not_local(r2r_node, service_name).and_then(|_| {
+                    Self::request_blocking(
+                        r2r_client,
+                        r2r_node,
+                        availability,
+                        &request,
+                        timeout,
+                        service_name,
+                    )
+                });
+                layers.result(&context, result, started)
             }
         }
     }
 
+    fn check_not_local(
+        r2r_node: &crate::SMutex<r2r::Node>,
+        service_name: &str,
+    ) -> crate::Result<()> {
+        let namespace = r2r_node.lock_err("r2r_node")?.namespace()?;
+        let name = crate::intra::topic_name(&namespace, service_name);
+        crate::blocking::check_not_local(crate::blocking::Endpoint::Service, &name)
+    }
+
+    fn request_blocking(
+        r2r_client: &Arc<r2r::Client<S>>,
+        r2r_node: &crate::SMutex<r2r::Node>,
+        availability: &crate::Availability,
+        request: &S::Request,
+        timeout: Option<std::time::Duration>,
+        service_name: &str,
+    ) -> crate::Result<S::Response> {
+        let deadline = timeout.map(|timeout| std::time::Instant::now() + timeout);
+        let timeout = timeout.unwrap_or_default();
+
+        if !availability.is_ready() {
+            let service_available = r2r::Node::is_available(&**r2r_client)?;
+            let spin = crate::blocking::AVAILABILITY_SPIN;
+            crate::blocking::spin_until(r2r_node, service_available, spin, deadline)
+                .ok_or_else(|| crate::availability::not_available(service_name, timeout))??;
+        }
+
+        let response_future = r2r_client.request(request)?;
+        let spin = crate::blocking::RESPONSE_SPIN;
+        let response = crate::blocking::spin_until(r2r_node, response_future, spin, deadline)
+            .ok_or_else(|| {
+                format!("service '{service_name}' did not answer within {timeout:?}")
+            })??;
+        Ok(response)
+    }
+
     pub fn call<F>(&self, request: S::Request, callback: F) -> crate::Result<()>
     where
         F: FnOnce(crate::Result<S::Response>) + 'static,
     {
-        match self {
-            Client::Empty => Err("service not initialized".to_string().into()),
-            Client::Defined {
-                r2r_client,
-                local_spawner,
-                ..
-            } => {
-                let r2r_client = r2r_client.clone();
+        let response = self.call_future(request)?;
+        self.spawn(async move { callback(response.await) })
+    }
 
-                local_spawner.spawn_local(async move {
-                    let result: crate::Result<S::Response> = async {
-                        let service_available = r2r::Node::is_available(&*r2r_client)?;
-                        service_available.await?;
+    /// Calls the service for each request, at most `max_in_flight` at a time,
+    /// `callback` gets the results in the order of the requests.
+    pub fn call_many<F>(
+        &self,
+        requests: Vec<S::Request>,
+        max_in_flight: usize,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        F: FnOnce(Vec<crate::Result<S::Response>>) + 'static,
+    {
+        let client = self.clone();
+        self.spawn(async move {
+            let results = crate::batch::call_many(requests, max_in_flight, |request| {
+                client.call_owned(request)
+            })
+            .await;
+            callback(crate::batch::into_results(results));
+        })
+    }
 
-                        let response_future = r2r_client.request(&request)?;
-                        let response = response_future.await?;
-                        Ok(response)
-                    }
-                    .await;
+    /// Sends `request` to all `clients`, `callback` gets the first successful response.
+    pub fn call_first<F>(clients: &[Self], request: S::Request, callback: F) -> crate::Result<()>
+    where
+        F: FnOnce(crate::Result<S::Response>) + 'static,
+    {
+        let calls: Vec<_> = clients
+            .iter()
+            .map(|client| client.call_owned(request.clone()))
+            .collect();
+        match clients.first() {
+            Some(client) => client.spawn(async move {
+                callback(crate::batch::into_result(
+                    crate::batch::call_first(calls).await,
+                ));
+            }),
+            None => Err("no client".to_string().into()),
+        }
+    }
 
-                    callback(result);
-                })?;
+    /// Sends `request` to all `clients`, `callback` gets their results in order.
+    pub fn call_all<F>(clients: &[Self], request: S::Request, callback: F) -> crate::Result<()>
+    where
+        F: FnOnce(Vec<crate::Result<S::Response>>) + 'static,
+    {
+        let calls: Vec<_> = clients
+            .iter()
+            .map(|client| client.call_owned(request.clone()))
+            .collect();
+        match clients.first() {
+            Some(client) => client.spawn(async move {
+                callback(crate::batch::into_results(
+                    crate::batch::call_all(calls).await,
+                ));
+            }),
+            None => Err("no client".to_string().into()),
+        }
+    }
 
+    /// Call sent through the layers, as an owned future for the batch calls.
+    fn call_owned(
+        &self,
+        request: S::Request,
+    ) -> impl Future<Output = crate::batch::CallResult<S::Response>> + 'static {
+        let response = self.call_future(request);
+        async move { response?.await }.map_err(|e| e.to_string())
+    }
+
+    /// Runs the request layers now and returns the call, its result going through the result layers.
+    fn call_future(
+        &self,
+        mut request: S::Request,
+    ) -> crate::Result<impl Future<Output = crate::Result<S::Response>> + 'static> {
+        let Client::Defined {
+            r2r_client,
+            service_name,
+            layers,
+            ..
+        } = self
+        else {
+            return Err("service not initialized".to_string().into());
+        };
+        let context = crate::LayerContext {
+            side: crate::LayerSide::Client,
+            service_name,
+        };
+        let started = layers.request(&context, &mut request)?;
+        let r2r_client = r2r_client.clone();
+        let service_name = service_name.clone();
+        let layers = layers.clone();
+
+        Ok(async move {
+            let result: crate::Result<S::Response> = async {
+                r2r::Node::is_available(&*r2r_client)?.await?;
+                let response = r2r_client.request(&request)?.await?;
+                Ok(response)
+            }
+            .await;
+            let context = crate::LayerContext {
+                side: crate::LayerSide::Client,
+                service_name: &service_name,
+            };
+            layers.result(&context, result, started)
+        })
+    }
+
+    fn spawn(&self, future: impl Future<Output = ()> + 'static) -> crate::Result<()> {
+        match self {
+            Client::Empty => Err("service not initialized".to_string().into()),
+            Client::Defined { local_spawner, .. } => {
+                local_spawner.spawn_local(future)?;
                 Ok(())
             }
         }
@@ -0,0 +1,100 @@
+use futures::task::SpawnExt;
+
+/// Client of an action server, running its callbacks on the thread pool.
+pub enum ActionClient<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    Empty,
+    Defined {
+        client: crate::ActionClient<A>,
+        pool: futures::executor::ThreadPool,
+    },
+}
+
+impl<A> Default for ActionClient<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    fn default() -> Self {
+        Self::Empty
+    }
+}
+
+impl<A> Clone for ActionClient<A>
+where
+    A: r2r::WrappedActionTypeSupport,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Empty => Self::Empty,
+            Self::Defined { client, pool } => Self::Defined {
+                client: client.clone(),
+                pool: pool.clone(),
+            },
+        }
+    }
+}
+
+impl<A> ActionClient<A>
+where
+    A: 'static + r2r::WrappedActionTypeSupport,
+    A::Result: Send,
+    A::Feedback: Send,
+{
+    /// Waits until the action server is available, at most `timeout`, the node being spun by another thread.
+    pub fn wait_for_server(&self, timeout: std::time::Duration) -> crate::Result<()> {
+        match self {
+            Self::Empty => Err("action client not initialized".into()),
+            Self::Defined { client, .. } => {
+                futures::executor::block_on(client.wait_for_server(timeout))
+            }
+        }
+    }
+
+    /// Sends `goal`, `feedback_callback` is called with each feedback of the goal
+    /// and `result_callback` with its final status and result, or the rejection of the goal.
+    pub fn send_goal<FF, RF>(
+        &self,
+        goal: A::Goal,
+        feedback_callback: FF,
+        result_callback: RF,
+    ) -> crate::Result<()>
+    where
+        A::Goal: Send,
+        A::Result: Sync,
+        FF: FnMut(A::Feedback) + Send + 'static,
+        RF: FnOnce(crate::Result<(r2r::GoalStatus, A::Result)>) + Send + 'static,
+    {
+        match self {
+            Self::Empty => Err("action client not initialized".into()),
+            Self::Defined { client, pool } => {
+                let client = client.clone();
+                pool.spawn(async move {
+                    let result = crate::action::follow_goal(client, goal, feedback_callback).await;
+                    result_callback(result.map_err(|e| e.into()));
+                })?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Sends `goal` and returns its final status and result,
+    /// parking the calling thread while the node is spun by another thread.
+    pub fn send_goal_blocking<FF>(
+        &self,
+        goal: A::Goal,
+        feedback_callback: FF,
+    ) -> crate::Result<(r2r::GoalStatus, A::Result)>
+    where
+        FF: FnMut(A::Feedback),
+    {
+        match self {
+            Self::Empty => Err("action client not initialized".into()),
+            Self::Defined { client, .. } => {
+                let follow = crate::action::follow_goal(client.clone(), goal, feedback_callback);
+                futures::executor::block_on(follow).map_err(|e| e.into())
+            }
+        }
+    }
+}
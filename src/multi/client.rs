@@ -1,7 +1,7 @@
+use crate::MutexLockErr;
 use futures::task::SpawnExt;
 use std::future::Future;
 use std::sync::Arc;
-use std::task::{Context, Poll};
 
 #[derive(Clone)]
 pub enum Client<S>
@@ -12,6 +12,10 @@ where
     Defined {
         r2r_client: Arc<r2r::Client<S>>,
         pool: futures::executor::ThreadPool,
+        r2r_node: crate::SMutex<r2r::Node>,
+        service_name: String,
+        layers: crate::Layers,
+        availability: crate::Availability,
     },
 }
 
@@ -30,45 +34,90 @@ where
     S::Request: Send + Sync + 'static,
     S::Response: Send + 'static,
 {
-    pub fn call_blocking(&self, request: S::Request) -> crate::Result<S::Response> {
+    /// Whether the service is available, as last seen by the node.
+    pub fn is_service_ready(&self) -> bool {
+        match self {
+            Client::Empty => false,
+            Client::Defined { availability, .. } => availability.is_ready(),
+        }
+    }
+
+    /// Calls `callback` with the new availability whenever the service appears or disappears.
+    pub fn on_availability_change<F>(&self, callback: F) -> crate::Result<()>
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        match self {
+            Client::Empty => Err("service not initialized".to_string().into()),
+            Client::Defined { availability, .. } => {
+                availability.on_change(callback);
+                Ok(())
+            }
+        }
+    }
+
+    /// Waits until the service is available, at most `timeout`,
+    /// parking the calling thread while the node is spun by another thread.
+    pub fn wait_for_service(&self, timeout: std::time::Duration) -> crate::Result<()> {
         match self {
             Client::Empty => Err("service not initialized".to_string().into()),
             Client::Defined {
-                r2r_client,
+                r2r_node,
+                service_name,
+                availability,
                 ..
             } => {
-                let r2r_client = r2r_client.clone();
-                let service_available = r2r::Node::is_available(&*r2r_client)?;
-                let mut service_available = Box::pin(service_available);
-
-                loop {
-                    let waker = futures::task::noop_waker_ref();
-                    let mut cx = Context::from_waker(waker);
-                    match service_available.as_mut().poll(&mut cx) {
-                        Poll::Ready(result) => {
-                            result?;
-                            break;
-                        }
-                        Poll::Pending => std::thread::sleep(std::time::Duration::from_millis(1)),
-                    }
+                if availability.is_ready() {
+                    return Ok(());
                 }
+                let logger = r2r_node.lock_err("r2r_node")?.logger().to_string();
+                r2r::log_info!(&logger, "waiting for service '{}'...", service_name);
+                futures::executor::block_on(crate::availability::timeout(
+                    availability.wait(),
+                    timeout,
+                ))
+                .ok_or_else(|| crate::availability::not_available(service_name, timeout))
+            }
+        }
+    }
 
-                let response_future = r2r_client.request(&request)?;
-                let mut response_future = Box::pin(response_future);
-
-                loop {
-                    let waker = futures::task::noop_waker_ref();
-                    let mut cx = Context::from_waker(waker);
-                    match response_future.as_mut().poll(&mut cx) {
-                        Poll::Ready(result) => return result.map_err(|e| e.into()),
-                        Poll::Pending => std::thread::sleep(std::time::Duration::from_millis(1)),
-                    }
-                }
+    pub fn call_blocking(&self, mut request: S::Request) -> crate::Result<S::Response> {
+        match self {
+            Client::Empty => Err("service not initialized".to_string().into()),
+            Client::Defined {
+                r2r_client,
+                service_name,
+                layers,
+                availability,
+                ..
+            } => {
+                let context = crate::LayerContext {
+                    side: crate::LayerSide::Client,
+                    service_name,
+                };
+                let started = layers.request(&context, &mut request)?;
+                let result = Self::request_blocking(r2r_client, availability, &request);
+                layers.result(&context, result, started)
             }
         }
     }
 
-    pub fn call<F>(&self, request: S::Request, callback: F) -> crate::Result<()>
+    /// Parks the calling thread until the response, the node being spun by another thread.
+    fn request_blocking(
+        r2r_client: &Arc<r2r::Client<S>>,
+        availability: &crate::Availability,
+        request: &S::Request,
+    ) -> crate::Result<S::Response> {
+        futures::executor::block_on(async {
+            if !availability.is_ready() {
+                r2r::Node::is_available(&**r2r_client)?.await?;
+            }
+            let response = r2r_client.request(request)?.await?;
+            Ok(response)
+        })
+    }
+
+    pub fn call<F>(&self, mut request: S::Request, callback: F) -> crate::Result<()>
     where
         F: FnOnce(crate::Result<S::Response>) + Send + 'static,
     {
@@ -77,9 +126,18 @@ where
             Client::Defined {
                 r2r_client,
                 pool,
+                service_name,
+                layers,
                 ..
             } => {
+                let context = crate::LayerContext {
+                    side: crate::LayerSide::Client,
+                    service_name,
+                };
+                let started = layers.request(&context, &mut request)?;
                 let r2r_client = r2r_client.clone();
+                let service_name = service_name.clone();
+                let layers = layers.clone();
 
                 pool.spawn(async move {
                     let result: crate::Result<S::Response> = async {
@@ -92,11 +150,117 @@ where
                     }
                     .await;
 
-                    callback(result);
+                    let context = crate::LayerContext {
+                        side: crate::LayerSide::Client,
+                        service_name: &service_name,
+                    };
+                    callback(layers.result(&context, result, started));
                 })?;
 
                 Ok(())
             }
         }
     }
+
+    /// Calls the service for each request, at most `max_in_flight` at a time,
+    /// `callback` gets the results in the order of the requests.
+    pub fn call_many<F>(
+        &self,
+        requests: Vec<S::Request>,
+        max_in_flight: usize,
+        callback: F,
+    ) -> crate::Result<()>
+    where
+        F: FnOnce(Vec<crate::Result<S::Response>>) + Send + 'static,
+    {
+        let client = self.clone();
+        self.spawn(async move {
+            let results = crate::batch::call_many(requests, max_in_flight, |request| {
+                client.call_owned(request)
+            })
+            .await;
+            callback(crate::batch::into_results(results));
+        })
+    }
+
+    /// Sends `request` to all `clients`, `callback` gets the first successful response.
+    pub fn call_first<F>(clients: &[Self], request: S::Request, callback: F) -> crate::Result<()>
+    where
+        F: FnOnce(crate::Result<S::Response>) + Send + 'static,
+    {
+        let calls: Vec<_> = clients
+            .iter()
+            .map(|client| client.call_owned(request.clone()))
+            .collect();
+        match clients.first() {
+            Some(client) => client.spawn(async move {
+                callback(crate::batch::into_result(
+                    crate::batch::call_first(calls).await,
+                ));
+            }),
+            None => Err("no client".to_string().into()),
+        }
+    }
+
+    /// Sends `request` to all `clients`, `callback` gets their results in order.
+    pub fn call_all<F>(clients: &[Self], request: S::Request, callback: F) -> crate::Result<()>
+    where
+        F: FnOnce(Vec<crate::Result<S::Response>>) + Send + 'static,
+    {
+        let calls: Vec<_> = clients
+            .iter()
+            .map(|client| client.call_owned(request.clone()))
+            .collect();
+        match clients.first() {
+            Some(client) => client.spawn(async move {
+                callback(crate::batch::into_results(
+                    crate::batch::call_all(calls).await,
+                ));
+            }),
+            None => Err("no client".to_string().into()),
+        }
+    }
+
+    /// Call sent through the layers, as an owned future for the batch calls.
+    fn call_owned(
+        &self,
+        mut request: S::Request,
+    ) -> impl Future<Output = crate::batch::CallResult<S::Response>> + Send + 'static {
+        let client = self.clone();
+        async move {
+            let Client::Defined {
+                r2r_client,
+                service_name,
+                layers,
+                ..
+            } = client
+            else {
+                return Err("service not initialized".to_string());
+            };
+            let context = crate::LayerContext {
+                side: crate::LayerSide::Client,
+                service_name: &service_name,
+            };
+            let started = layers.request(&context, &mut request)?;
+            let result: crate::Result<S::Response> = async {
+                r2r::Node::is_available(&*r2r_client)?.await?;
+                let response = r2r_client.request(&request)?.await?;
+                Ok(response)
+            }
+            .await;
+            layers
+                .result(&context, result, started)
+                .map_err(|e| e.to_string())
+        }
+    }
+
+    fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) -> crate::Result<()> {
+        match self {
+            Client::Empty => Err("service not initialized".to_string().into()),
+            Client::Defined { pool, .. } => {
+                pool.spawn(future)?;
+                Ok(())
+            }
+        }
+    }
 }
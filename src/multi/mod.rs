@@ -4,4 +4,7 @@ pub use node::*;
 pub mod client;
 pub use client::*;
 
-pub use crate::future::Publisher;
+pub mod action;
+pub use action::*;
+
+pub use crate::future::{Publisher, UntypedPublisher};
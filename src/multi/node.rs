@@ -1,7 +1,12 @@
 use std::sync::Arc;
 
 pub use crate::api::NodeMulti;
-pub use crate::{MutexCreate, MutexLockErr, MutexLockOrLog, Result, SMutex};
+pub use crate::{
+    ActionGoal, ClockType, DeliveryPolicy, GoalOutcome, Latest, Layer, LayerContext, LayerMessage,
+    LayerSide, Loaned, LoggingLayer, MutexCreate, MutexLockErr, MutexLockOrLog, Overflow,
+    PublisherOptions, RawMessage, ReceiveInfo, Responder, Result, SMutex, ServiceErrorPolicy,
+    Stamped, SubscriptionOptions, SubscriptionStats, SyncPolicy, ThrottlePolicy, Time,
+};
 use futures::StreamExt;
 use futures::executor::ThreadPool;
 use futures::task::SpawnExt;
@@ -9,11 +14,14 @@ use futures::task::SpawnExt;
 pub struct Node {
     r2r_node: SMutex<r2r::Node>,
     pool: ThreadPool,
+    layers: crate::Layers,
 }
 
 impl NodeMulti for Node {
     type Publisher<M: r2r::WrappedTypesupport> = crate::multi::Publisher<M>;
     type Client<S: r2r::WrappedServiceTypeSupport> = crate::multi::Client<S>;
+    type UntypedPublisher = crate::multi::UntypedPublisher;
+    type ActionClient<A: r2r::WrappedActionTypeSupport> = crate::multi::ActionClient<A>;
 
     fn create(name: &str, namespace: &str) -> Result<Self> {
         let ctx = r2r::Context::create()?;
@@ -24,6 +32,7 @@ impl NodeMulti for Node {
         let node = Self {
             r2r_node,
             pool,
+            layers: crate::Layers::default(),
         };
         Ok(node)
     }
@@ -33,14 +42,11 @@ impl NodeMulti for Node {
     }
 
     fn now(&self) -> std::time::Duration {
-        let node = self.r2r_node.lock_or_log("r2r_node");
-        let ros_clock = node.get_ros_clock();
-        let mut clock = ros_clock.lock_or_log("clock");
-        let now = clock.get_now().unwrap_or_else(|e| {
-            eprintln!("[WARN] get_now() error: {e}");
-            std::time::Duration::from_secs(0)
-        });
-        now
+        self.now_with(crate::ClockType::Ros).since_epoch()
+    }
+
+    fn now_with(&self, clock_type: crate::ClockType) -> crate::Time {
+        crate::time::now_with(&self.r2r_node, clock_type)
     }
 
     fn logger(&self) -> String {
@@ -292,6 +298,64 @@ impl NodeMulti for Node {
         Ok(Self::Publisher::Defined {
             logger,
             r2r_publisher,
+            throttle: None,
+            intra_process: None,
+        })
+    }
+
+    fn create_publisher_with_options<M>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::PublisherOptions,
+    ) -> Result<Self::Publisher<M>>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+    {
+        let logger = self.logger();
+
+        let r2r_publisher = {
+            let mut r2r_node = self.r2r_node.lock_err("r2r_node")?;
+            let r2_publisher = r2r_node.create_publisher(topic, qos_profile)?;
+            SMutex::create(r2_publisher)
+        };
+
+        let parts = crate::options::publisher_parts(
+            &self.r2r_node,
+            topic,
+            &options,
+            &r2r_publisher,
+            &logger,
+        )?;
+        if let Some(flush) = parts.flush {
+            self.pool.spawn(flush)?;
+        }
+
+        Ok(Self::Publisher::Defined {
+            logger,
+            r2r_publisher,
+            throttle: parts.throttle,
+            intra_process: parts.intra_process,
+        })
+    }
+
+    fn create_untyped_publisher(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> Result<Self::UntypedPublisher> {
+        let logger = self.logger();
+
+        let r2r_publisher = {
+            let mut r2r_node = self.r2r_node.lock_err("r2r_node")?;
+            let r2_publisher = r2r_node.create_publisher_untyped(topic, topic_type, qos_profile)?;
+            SMutex::create(r2_publisher)
+        };
+
+        Ok(Self::UntypedPublisher::Defined {
+            logger,
+            r2r_publisher,
         })
     }
 
@@ -514,188 +578,145 @@ impl NodeMulti for Node {
         Ok(())
     }
 
-    fn create_service_0<S, F>(
+    fn create_subscription_with_options_0<M, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
     ) -> Result<()>
     where
-        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
-        F: Send + Sync + 'static + Fn(S::Request) -> S::Response,
+        M: Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static + Fn(M),
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            node.create_service::<S>(service_name, qos_profile)?
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response = callback(request.message.clone());
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(msg);
+                    })
+                })
+                .await;
         })?;
         Ok(())
     }
 
-    fn create_service_1<S, T, F>(
+    fn create_subscription_with_options_1<M, T, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data: T,
     ) -> Result<()>
     where
-        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        M: Send + 'static + r2r::WrappedTypesupport,
         T: Clone + Send + Sync + 'static,
-        F: Send + Sync + 'static + Fn(T, S::Request) -> S::Response,
+        F: Send + Sync + 'static + Fn(T, M),
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            node.create_service::<S>(service_name, qos_profile)?
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response = callback(data.clone(), request.message.clone());
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    let data = data.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(data, msg);
+                    })
+                })
+                .await;
         })?;
         Ok(())
     }
 
-    fn create_service_2<S, T1, T2, F>(
+    fn create_subscription_with_options_2<M, T1, T2, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
     ) -> Result<()>
     where
-        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        M: Send + 'static + r2r::WrappedTypesupport,
         T1: Clone + Send + Sync + 'static,
         T2: Clone + Send + Sync + 'static,
-        F: Send + Sync + 'static + Fn(T1, T2, S::Request) -> S::Response,
+        F: Send + Sync + 'static + Fn(T1, T2, M),
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            node.create_service::<S>(service_name, qos_profile)?
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response =
-                            callback(data_1.clone(), data_2.clone(), request.message.clone());
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(data_1, data_2, msg);
+                    })
+                })
+                .await;
         })?;
         Ok(())
     }
 
-    fn create_service_3<S, T1, T2, T3, F>(
+    fn create_subscription_with_options_3<M, T1, T2, T3, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
         data_3: T3,
     ) -> Result<()>
     where
-        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        M: Send + 'static + r2r::WrappedTypesupport,
         T1: Clone + Send + Sync + 'static,
         T2: Clone + Send + Sync + 'static,
         T3: Clone + Send + Sync + 'static,
-        F: Send + Sync + 'static + Fn(T1, T2, T3, S::Request) -> S::Response,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, M),
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            node.create_service::<S>(service_name, qos_profile)?
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response = callback(
-                            data_1.clone(),
-                            data_2.clone(),
-                            data_3.clone(),
-                            request.message.clone(),
-                        );
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    let data_3 = data_3.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(data_1, data_2, data_3, msg);
+                    })
+                })
+                .await;
         })?;
         Ok(())
     }
 
-    fn create_service_4<S, T1, T2, T3, T4, F>(
+    fn create_subscription_with_options_4<M, T1, T2, T3, T4, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
@@ -703,53 +724,40 @@ impl NodeMulti for Node {
         data_4: T4,
     ) -> Result<()>
     where
-        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        M: Send + 'static + r2r::WrappedTypesupport,
         T1: Clone + Send + Sync + 'static,
         T2: Clone + Send + Sync + 'static,
         T3: Clone + Send + Sync + 'static,
         T4: Clone + Send + Sync + 'static,
-        F: Send + Sync + 'static + Fn(T1, T2, T3, T4, S::Request) -> S::Response,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, T4, M),
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            node.create_service::<S>(service_name, qos_profile)?
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response = callback(
-                            data_1.clone(),
-                            data_2.clone(),
-                            data_3.clone(),
-                            data_4.clone(),
-                            request.message.clone(),
-                        );
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    let data_3 = data_3.clone();
+                    let data_4 = data_4.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(data_1, data_2, data_3, data_4, msg);
+                    })
+                })
+                .await;
         })?;
         Ok(())
     }
 
-    fn create_service_5<S, T1, T2, T3, T4, T5, F>(
+    fn create_subscription_with_options_5<M, T1, T2, T3, T4, T5, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
         callback: F,
         data_1: T1,
         data_2: T2,
@@ -758,72 +766,1018 @@ impl NodeMulti for Node {
         data_5: T5,
     ) -> Result<()>
     where
-        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        M: Send + 'static + r2r::WrappedTypesupport,
         T1: Clone + Send + Sync + 'static,
         T2: Clone + Send + Sync + 'static,
         T3: Clone + Send + Sync + 'static,
         T4: Clone + Send + Sync + 'static,
         T5: Clone + Send + Sync + 'static,
-        F: Send + Sync + 'static + Fn(T1, T2, T3, T4, T5, S::Request) -> S::Response,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, T4, T5, M),
     {
-        let mut service = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            node.create_service::<S>(service_name, qos_profile)?
-        };
+        let subscription =
+            crate::options::subscribe::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        let r2r_node_mutex = self.r2r_node.clone();
-        let service_name = service_name.to_string();
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
         self.pool.spawn(async move {
-            loop {
-                match service.next().await {
-                    Some(request) => {
-                        let response = callback(
-                            data_1.clone(),
-                            data_2.clone(),
-                            data_3.clone(),
-                            data_4.clone(),
-                            data_5.clone(),
-                            request.message.clone(),
-                        );
-                        if let Err(e) = request.respond(response) {
-                            r2r::log_error!(
-                                r2r_node_mutex
-                                    .lock_or_log("r2r_node.create_service")
-                                    .logger(),
-                                "service response error (service_name='{}'): {}",
-                                service_name,
-                                e
-                            );
-                        }
-                    }
-                    None => break,
-                }
-            }
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    let data_3 = data_3.clone();
+                    let data_4 = data_4.clone();
+                    let data_5 = data_5.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(data_1, data_2, data_3, data_4, data_5, msg);
+                    })
+                })
+                .await;
         })?;
         Ok(())
     }
 
-    fn create_client<S>(
+    fn create_subscription_shared_0<M, F>(
         &self,
-        service_name: &str,
+        topic: &str,
         qos_profile: r2r::QosProfile,
-    ) -> Result<Self::Client<S>>
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+    ) -> Result<()>
     where
-        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
-        S::Request: Send + Sync + 'static,
-        S::Response: Send + 'static,
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static + Fn(Arc<M>),
     {
-        let r2r_client = {
-            let mut node = self.r2r_node.lock_err("r2r_node")?;
-            node.create_client::<S>(service_name, qos_profile)?
-        };
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
 
-        Ok(Self::Client::Defined {
-            r2r_client: Arc::new(r2r_client),
-            pool: self.pool.clone(),
-        })
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(msg);
+                    })
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_shared_1<M, T, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data: T,
+    ) -> Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T, Arc<M>),
+    {
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
+
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    let data = data.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(data, msg);
+                    })
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_shared_2<M, T1, T2, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, Arc<M>),
+    {
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
+
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(data_1, data_2, msg);
+                    })
+                })
+                .await;
+        })?;
+        Ok(())
     }
 
+    fn create_subscription_shared_3<M, T1, T2, T3, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, Arc<M>),
+    {
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
+
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    let data_3 = data_3.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(data_1, data_2, data_3, msg);
+                    })
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_shared_4<M, T1, T2, T3, T4, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, T4, Arc<M>),
+    {
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
+
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    let data_3 = data_3.clone();
+                    let data_4 = data_4.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(data_1, data_2, data_3, data_4, msg);
+                    })
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_shared_5<M, T1, T2, T3, T4, T5, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        options: crate::SubscriptionOptions<M>,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> Result<()>
+    where
+        M: Send + Sync + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        T5: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, T4, T5, Arc<M>),
+    {
+        let subscription =
+            crate::options::subscribe_shared::<M>(&self.r2r_node, topic, qos_profile, &options)?;
+
+        let callback = Arc::new(callback);
+        let pool = self.pool.clone();
+        self.pool.spawn(async move {
+            subscription
+                .dispatch(move |msg| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    let data_3 = data_3.clone();
+                    let data_4 = data_4.clone();
+                    let data_5 = data_5.clone();
+                    crate::spawn::spawn_pooled(&pool, "subscription callback", async move {
+                        (*callback)(data_1, data_2, data_3, data_4, data_5, msg);
+                    })
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_0<M, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static + Fn(M, crate::ReceiveInfo),
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            subscription
+                .for_each(move |(msg, info)| {
+                    let callback = callback.clone();
+                    async move {
+                        (*callback)(msg, info);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_1<M, T, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T, M, crate::ReceiveInfo),
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            subscription
+                .for_each(move |(msg, info)| {
+                    let callback = callback.clone();
+                    let data = data.clone();
+                    async move {
+                        (*callback)(data, msg, info);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_2<M, T1, T2, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, M, crate::ReceiveInfo),
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            subscription
+                .for_each(move |(msg, info)| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    async move {
+                        (*callback)(data_1, data_2, msg, info);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_3<M, T1, T2, T3, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, M, crate::ReceiveInfo),
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            subscription
+                .for_each(move |(msg, info)| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    let data_3 = data_3.clone();
+                    async move {
+                        (*callback)(data_1, data_2, data_3, msg, info);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_4<M, T1, T2, T3, T4, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, T4, M, crate::ReceiveInfo),
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            subscription
+                .for_each(move |(msg, info)| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    let data_3 = data_3.clone();
+                    let data_4 = data_4.clone();
+                    async move {
+                        (*callback)(data_1, data_2, data_3, data_4, msg, info);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_subscription_with_info_5<M, T1, T2, T3, T4, T5, F>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> Result<()>
+    where
+        M: Send + 'static + r2r::WrappedTypesupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        T5: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, T4, T5, M, crate::ReceiveInfo),
+    {
+        let subscription =
+            crate::receive_info::subscribe_with_info::<M>(&self.r2r_node, topic, qos_profile)?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            subscription
+                .for_each(move |(msg, info)| {
+                    let callback = callback.clone();
+                    let data_1 = data_1.clone();
+                    let data_2 = data_2.clone();
+                    let data_3 = data_3.clone();
+                    let data_4 = data_4.clone();
+                    let data_5 = data_5.clone();
+                    async move {
+                        (*callback)(data_1, data_2, data_3, data_4, data_5, msg, info);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_untyped_subscription<F>(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> Result<()>
+    where
+        F: Send + Sync + 'static + Fn(serde_json::Value),
+    {
+        let subscription = crate::untyped::subscribe_untyped(
+            &self.r2r_node,
+            topic,
+            topic_type,
+            qos_profile,
+            self.logger(),
+        )?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            subscription
+                .for_each(move |msg| {
+                    let callback = callback.clone();
+                    async move {
+                        (*callback)(msg);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_raw_subscription<F>(
+        &self,
+        topic: &str,
+        topic_type: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> Result<()>
+    where
+        F: Send + Sync + 'static + Fn(RawMessage),
+    {
+        let subscription =
+            crate::untyped::subscribe_raw(&self.r2r_node, topic, topic_type, qos_profile)?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            subscription
+                .for_each(move |msg| {
+                    let callback = callback.clone();
+                    async move {
+                        (*callback)(msg);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    //-------------------------------------------------- Synchronizer --------------------------------------------------
+
+    fn create_synchronizer_2<M1, M2, F>(
+        &self,
+        topics: [&str; 2],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static + Fn((M1, M2)),
+    {
+        let synchronized = crate::synchronizer::synchronize_2::<M1, M2>(
+            &self.r2r_node,
+            topics,
+            qos_profile,
+            policy,
+        )?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            synchronized
+                .for_each(move |msgs| {
+                    let callback = callback.clone();
+                    async move {
+                        (*callback)(msgs);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_synchronizer_3<M1, M2, M3, F>(
+        &self,
+        topics: [&str; 3],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static + Fn((M1, M2, M3)),
+    {
+        let synchronized = crate::synchronizer::synchronize_3::<M1, M2, M3>(
+            &self.r2r_node,
+            topics,
+            qos_profile,
+            policy,
+        )?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            synchronized
+                .for_each(move |msgs| {
+                    let callback = callback.clone();
+                    async move {
+                        (*callback)(msgs);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_synchronizer_4<M1, M2, M3, M4, F>(
+        &self,
+        topics: [&str; 4],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M4: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static + Fn((M1, M2, M3, M4)),
+    {
+        let synchronized = crate::synchronizer::synchronize_4::<M1, M2, M3, M4>(
+            &self.r2r_node,
+            topics,
+            qos_profile,
+            policy,
+        )?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            synchronized
+                .for_each(move |msgs| {
+                    let callback = callback.clone();
+                    async move {
+                        (*callback)(msgs);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_synchronizer_5<M1, M2, M3, M4, M5, F>(
+        &self,
+        topics: [&str; 5],
+        qos_profile: r2r::QosProfile,
+        policy: crate::SyncPolicy,
+        callback: F,
+    ) -> Result<()>
+    where
+        M1: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M2: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M3: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M4: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        M5: crate::Stamped + Send + 'static + r2r::WrappedTypesupport,
+        F: Send + Sync + 'static + Fn((M1, M2, M3, M4, M5)),
+    {
+        let synchronized = crate::synchronizer::synchronize_5::<M1, M2, M3, M4, M5>(
+            &self.r2r_node,
+            topics,
+            qos_profile,
+            policy,
+        )?;
+
+        let callback = Arc::new(callback);
+        self.pool.spawn(async move {
+            synchronized
+                .for_each(move |msgs| {
+                    let callback = callback.clone();
+                    async move {
+                        (*callback)(msgs);
+                    }
+                })
+                .await;
+        })?;
+        Ok(())
+    }
+
+    fn create_latest<M>(
+        &self,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> Result<crate::Latest<M>>
+    where
+        M: Clone + Send + Sync + 'static + r2r::WrappedTypesupport,
+    {
+        let (latest, feed) = crate::latest::create_latest::<M>(&self.r2r_node, topic, qos_profile)?;
+        self.pool.spawn(feed)?;
+        Ok(latest)
+    }
+
+    fn add_layer<L: crate::Layer + 'static>(&mut self, layer: L) {
+        self.layers.push(layer);
+    }
+
+    fn create_service_0<S, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> Result<()>
+    where
+        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        F: Send + Sync + 'static + Fn(S::Request) -> S::Response,
+    {
+        self.create_service_try_0::<S, _, _, std::convert::Infallible>(
+            service_name,
+            qos_profile,
+            crate::ServiceErrorPolicy::default(),
+            move |req| Ok(callback(req)),
+        )
+    }
+
+    fn create_service_1<S, T, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data: T,
+    ) -> Result<()>
+    where
+        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        T: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T, S::Request) -> S::Response,
+    {
+        self.create_service_0::<S, _>(service_name, qos_profile, move |req| {
+            callback(data.clone(), req)
+        })
+    }
+
+    fn create_service_2<S, T1, T2, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+    ) -> Result<()>
+    where
+        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, S::Request) -> S::Response,
+    {
+        self.create_service_0::<S, _>(service_name, qos_profile, move |req| {
+            callback(data_1.clone(), data_2.clone(), req)
+        })
+    }
+
+    fn create_service_3<S, T1, T2, T3, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+    ) -> Result<()>
+    where
+        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, S::Request) -> S::Response,
+    {
+        self.create_service_0::<S, _>(service_name, qos_profile, move |req| {
+            callback(data_1.clone(), data_2.clone(), data_3.clone(), req)
+        })
+    }
+
+    fn create_service_4<S, T1, T2, T3, T4, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+    ) -> Result<()>
+    where
+        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, T4, S::Request) -> S::Response,
+    {
+        self.create_service_0::<S, _>(service_name, qos_profile, move |req| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                data_4.clone(),
+                req,
+            )
+        })
+    }
+
+    fn create_service_5<S, T1, T2, T3, T4, T5, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+        data_1: T1,
+        data_2: T2,
+        data_3: T3,
+        data_4: T4,
+        data_5: T5,
+    ) -> Result<()>
+    where
+        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        T1: Clone + Send + Sync + 'static,
+        T2: Clone + Send + Sync + 'static,
+        T3: Clone + Send + Sync + 'static,
+        T4: Clone + Send + Sync + 'static,
+        T5: Clone + Send + Sync + 'static,
+        F: Send + Sync + 'static + Fn(T1, T2, T3, T4, T5, S::Request) -> S::Response,
+    {
+        self.create_service_0::<S, _>(service_name, qos_profile, move |req| {
+            callback(
+                data_1.clone(),
+                data_2.clone(),
+                data_3.clone(),
+                data_4.clone(),
+                data_5.clone(),
+                req,
+            )
+        })
+    }
+
+    fn create_service_try_0<S, F, R, E>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        policy: crate::ServiceErrorPolicy<S::Response>,
+        callback: F,
+    ) -> Result<()>
+    where
+        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        F: Send + Sync + 'static + Fn(S::Request) -> R,
+        R: Into<std::result::Result<S::Response, E>>,
+        E: std::fmt::Display,
+    {
+        let mut service_stream = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            node.create_service::<S>(service_name, qos_profile)?
+        };
+
+        let service = crate::service::ServiceContext::<S>::new(
+            self.r2r_node.clone(),
+            service_name,
+            policy,
+            self.layers.clone(),
+        );
+        self.pool.spawn(async move {
+            while let Some(request) = service_stream.next().await {
+                let result = service.request(&request).and_then(|(message, started)| {
+                    service.response(callback(message).into(), started)
+                });
+                service.respond(request, result);
+            }
+        })?;
+        Ok(())
+    }
+
+    fn create_service_concurrent_0<S, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        max_concurrent: usize,
+        callback: F,
+    ) -> Result<()>
+    where
+        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        F: Send + Sync + 'static + Fn(S::Request) -> S::Response,
+    {
+        let service_stream = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            node.create_service::<S>(service_name, qos_profile)?
+        };
+
+        let service = crate::service::ServiceContext::<S>::new(
+            self.r2r_node.clone(),
+            service_name,
+            crate::ServiceErrorPolicy::default(),
+            self.layers.clone(),
+        );
+        let pool = self.pool.clone();
+        let callback = Arc::new(callback);
+        self.pool.spawn(service_stream.for_each_concurrent(
+            max_concurrent.max(1),
+            move |request| {
+                let service = service.clone();
+                let callback = callback.clone();
+                let handle = async move {
+                    let result = service.request(&request).and_then(|(message, started)| {
+                        service.response(
+                            Ok::<_, std::convert::Infallible>(callback(message)),
+                            started,
+                        )
+                    });
+                    service.respond(request, result);
+                };
+                crate::spawn::spawn_pooled(&pool, "service request", handle)
+            },
+        ))?;
+        Ok(())
+    }
+
+    fn create_service_deferred_0<S, F>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+        callback: F,
+    ) -> Result<()>
+    where
+        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        F: Send + Sync + 'static + Fn(S::Request, crate::Responder<S>),
+    {
+        let mut service_stream = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            node.create_service::<S>(service_name, qos_profile)?
+        };
+
+        let service = crate::service::ServiceContext::<S>::new(
+            self.r2r_node.clone(),
+            service_name,
+            crate::ServiceErrorPolicy::default(),
+            self.layers.clone(),
+        );
+        self.pool.spawn(async move {
+            while let Some(request) = service_stream.next().await {
+                match service.request(&request) {
+                    Ok((message, started)) => {
+                        let responder = crate::Responder::new(request, service.clone(), started);
+                        callback(message, responder);
+                    }
+                    Err(e) => service.respond(request, Err(e)),
+                }
+            }
+        })?;
+        Ok(())
+    }
+
+    fn create_client<S>(
+        &self,
+        service_name: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> Result<Self::Client<S>>
+    where
+        S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+        S::Request: Send + Sync + 'static,
+        S::Response: Send + 'static,
+    {
+        let r2r_client = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            Arc::new(node.create_client::<S>(service_name, qos_profile)?)
+        };
+
+        let (availability, watcher) = crate::Availability::new();
+        self.pool.spawn(crate::availability::watch(
+            Arc::downgrade(&r2r_client),
+            self.r2r_node.clone(),
+            watcher,
+        ))?;
+
+        Ok(Self::Client::Defined {
+            r2r_client,
+            pool: self.pool.clone(),
+            r2r_node: self.r2r_node.clone(),
+            service_name: service_name.to_string(),
+            layers: self.layers.clone(),
+            availability,
+        })
+    }
+
+    //-------------------------------------------------- Action --------------------------------------------------
+
+    fn create_action_server_0<A, G, C, F>(
+        &self,
+        action_name: &str,
+        goal_callback: G,
+        cancel_callback: C,
+        callback: F,
+    ) -> Result<()>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        G: Send + 'static + Fn(&A::Goal) -> bool,
+        C: Send + Sync + 'static + Fn(&A::Goal) -> bool,
+        F: Send + Sync + 'static + Fn(crate::ActionGoal<A>),
+    {
+        let requests = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            node.create_action_server::<A>(action_name)?
+        };
+        let callback = Arc::new(callback);
+        let callback_pool = self.pool.clone();
+        let pool = self.pool.clone();
+        let server = crate::action::serve(
+            requests,
+            self.logger(),
+            goal_callback,
+            cancel_callback,
+            move |goal| {
+                let callback = callback.clone();
+                callback_pool.spawn_ok(async move { callback(goal) });
+                futures::future::ready(None)
+            },
+            move |goal| crate::spawn::spawn_pooled(&pool, "action goal", goal),
+        );
+        self.pool.spawn(server)?;
+        Ok(())
+    }
+
+    fn create_action_client<A>(&self, action_name: &str) -> Result<Self::ActionClient<A>>
+    where
+        A: 'static + r2r::WrappedActionTypeSupport,
+        A::Result: Send,
+        A::Feedback: Send,
+    {
+        let r2r_client = {
+            let mut node = self.r2r_node.lock_err("r2r_node")?;
+            node.create_action_client::<A>(action_name)?
+        };
+        Ok(Self::ActionClient::Defined {
+            client: crate::ActionClient::new(r2r_client, action_name),
+            pool: self.pool.clone(),
+        })
+    }
+
+    //-------------------------------------------------- Spin --------------------------------------------------
+
     fn spin(&mut self, timeout: std::time::Duration) {
         loop {
             {
@@ -0,0 +1,422 @@
+use std::borrow::Borrow;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use futures::stream::BoxStream;
+use futures::{Future, FutureExt, StreamExt};
+
+use crate::mailbox::{Mailbox, MailboxStream};
+use crate::{MutexCreate, MutexLockErr, SMutex, Throttle, ThrottlePolicy};
+
+//-------------------------------------------------- Publisher --------------------------------------------------
+
+#[derive(Clone, Debug, Default)]
+pub struct PublisherOptions {
+    throttle: Option<(f64, ThrottlePolicy)>,
+    intra_process: bool,
+}
+
+impl PublisherOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Publishes at most `max_hz` messages per second.
+    pub fn throttle(mut self, max_hz: f64, policy: ThrottlePolicy) -> Self {
+        self.throttle = Some((max_hz, policy));
+        self
+    }
+
+    /// Also hands the messages to the in-process subscriptions created with `SubscriptionOptions::intra_process`
+    /// or `create_subscription_shared_*`, which then ignore the DDS copies of the topic.
+    pub fn intra_process(mut self) -> Self {
+        self.intra_process = true;
+        self
+    }
+}
+
+pub(crate) struct PublisherParts<M> {
+    pub throttle: Option<SMutex<Throttle<M>>>,
+    /// Task publishing the messages kept by a `KeepLatest` throttle, to be spawned by the node.
+    pub flush: Option<futures::future::BoxFuture<'static, ()>>,
+    pub intra_process: Option<Arc<crate::intra::IntraPublisher>>,
+}
+
+pub(crate) fn publisher_parts<M>(
+    r2r_node: &SMutex<r2r::Node>,
+    topic: &str,
+    options: &PublisherOptions,
+    r2r_publisher: &SMutex<r2r::Publisher<M>>,
+    logger: &str,
+) -> crate::Result<PublisherParts<M>>
+where
+    M: r2r::WrappedTypesupport + Send + 'static,
+{
+    let intra_process = if options.intra_process {
+        let namespace = r2r_node.lock_err("r2r_node")?.namespace()?;
+        let topic = crate::intra::topic_name(&namespace, topic);
+        Some(Arc::new(crate::intra::IntraPublisher::new(topic)))
+    } else {
+        None
+    };
+
+    let Some((max_hz, policy)) = options.throttle else {
+        return Ok(PublisherParts {
+            throttle: None,
+            flush: None,
+            intra_process,
+        });
+    };
+
+    let throttle = Throttle::new(max_hz, policy);
+    let period = throttle.period();
+    let throttle = SMutex::create(throttle);
+
+    let flush = if policy == ThrottlePolicy::KeepLatest && !period.is_zero() {
+        let timer = {
+            let mut node = r2r_node.lock_err("r2r_node")?;
+            node.create_wall_timer(period)?
+        };
+        let flush = crate::throttle::flush_publisher(
+            timer,
+            throttle.clone(),
+            r2r_publisher.clone(),
+            intra_process.clone(),
+            logger.to_string(),
+        );
+        Some(flush.boxed())
+    } else {
+        None
+    };
+
+    Ok(PublisherParts {
+        throttle: Some(throttle),
+        flush,
+        intra_process,
+    })
+}
+
+//-------------------------------------------------- Subscription --------------------------------------------------
+
+/// What to drop when a bounded subscription queue is full.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Overflow {
+    #[default]
+    DropOldest,
+    DropNewest,
+}
+
+/// How the messages of a subscription are handed to its callback.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DeliveryPolicy {
+    /// One message at a time, in order, without dropping any message.
+    #[default]
+    Sequential,
+    /// Only the most recent message is kept while the callback is busy.
+    LatestOnly,
+    /// At most `n` messages wait while the callback is busy.
+    Bounded(usize, Overflow),
+    /// Up to `max_in_flight` callbacks run at the same time, messages may complete out of order.
+    Concurrent(usize),
+}
+
+/// Counters shared with a subscription, cheap to clone.
+#[derive(Clone, Debug, Default)]
+pub struct SubscriptionStats {
+    received: Arc<AtomicU64>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl SubscriptionStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Messages received from the topic.
+    pub fn received(&self) -> u64 {
+        self.received.load(Ordering::Relaxed)
+    }
+
+    /// Messages dropped by the throttle or the delivery policy.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn add_received(&self) {
+        self.received.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_dropped(&self, n: u64) {
+        self.dropped.fetch_add(n, Ordering::Relaxed);
+    }
+}
+
+type Filter<M> = Arc<dyn Fn(&M) -> bool + Send + Sync>;
+
+pub struct SubscriptionOptions<M> {
+    throttle: Option<(f64, ThrottlePolicy)>,
+    policy: DeliveryPolicy,
+    stats: SubscriptionStats,
+    filter: Option<Filter<M>>,
+    intra_process: Option<crate::intra::SubscribeOwned<M>>,
+}
+
+impl<M> Default for SubscriptionOptions<M> {
+    fn default() -> Self {
+        Self {
+            throttle: None,
+            policy: DeliveryPolicy::default(),
+            stats: SubscriptionStats::default(),
+            filter: None,
+            intra_process: None,
+        }
+    }
+}
+
+impl<M> Clone for SubscriptionOptions<M> {
+    fn clone(&self) -> Self {
+        Self {
+            throttle: self.throttle,
+            policy: self.policy,
+            stats: self.stats.clone(),
+            filter: self.filter.clone(),
+            intra_process: self.intra_process,
+        }
+    }
+}
+
+impl<M> std::fmt::Debug for SubscriptionOptions<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubscriptionOptions")
+            .field("throttle", &self.throttle)
+            .field("policy", &self.policy)
+            .field("stats", &self.stats)
+            .field("filter", &self.filter.is_some())
+            .field("intra_process", &self.intra_process.is_some())
+            .finish()
+    }
+}
+
+impl<M> SubscriptionOptions<M> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Delivers at most `max_hz` messages per second.
+    /// With `ThrottlePolicy::KeepLatest` the latest message of each period is delivered.
+    pub fn throttle(mut self, max_hz: f64, policy: ThrottlePolicy) -> Self {
+        self.throttle = Some((max_hz, policy));
+        self
+    }
+
+    pub fn policy(mut self, policy: DeliveryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Counts the received and dropped messages into `stats`.
+    pub fn stats(mut self, stats: &SubscriptionStats) -> Self {
+        self.stats = stats.clone();
+        self
+    }
+
+    /// Only the messages matching `predicate` reach the throttle, the delivery policy and the callback.
+    pub fn filter<P>(mut self, predicate: P) -> Self
+    where
+        P: Fn(&M) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Arc::new(predicate));
+        self
+    }
+}
+
+impl<M> SubscriptionOptions<M>
+where
+    M: Clone + Send + Sync + 'static,
+{
+    /// Also receives the messages of the in-process publishers created with `PublisherOptions::intra_process`,
+    /// shared without serialization.
+    pub fn intra_process(mut self) -> Self {
+        self.intra_process = Some(crate::intra::subscribe_owned::<M>);
+        self
+    }
+}
+
+/// Subscription stream with its delivery policy.
+pub(crate) struct Subscribed<M> {
+    stream: BoxStream<'static, M>,
+    policy: DeliveryPolicy,
+    stats: SubscriptionStats,
+}
+
+impl<M: Send + 'static> Subscribed<M> {
+    /// Feeds the messages to `handler` according to the delivery policy.
+    pub async fn dispatch<H, R>(self, handler: H)
+    where
+        H: FnMut(M) -> R,
+        R: Future<Output = ()>,
+    {
+        let Self {
+            stream,
+            policy,
+            stats,
+        } = self;
+        let (capacity, overflow) = match policy {
+            DeliveryPolicy::Sequential => return stream.for_each(handler).await,
+            DeliveryPolicy::Concurrent(max_in_flight) => {
+                return stream
+                    .for_each_concurrent(max_in_flight.max(1), handler)
+                    .await;
+            }
+            DeliveryPolicy::LatestOnly => (1, Overflow::DropOldest),
+            DeliveryPolicy::Bounded(capacity, overflow) => (capacity, overflow),
+        };
+
+        let mailbox = Arc::new(Mailbox::new(capacity, overflow));
+        let pump = {
+            let mailbox = mailbox.clone();
+            async move {
+                stream
+                    .for_each(|msg| {
+                        if !mailbox.push(msg) {
+                            stats.add_dropped(1);
+                        }
+                        futures::future::ready(())
+                    })
+                    .await;
+                mailbox.close();
+            }
+        };
+        let deliver = MailboxStream(mailbox).for_each(handler);
+        futures::future::join(pump, deliver).await;
+    }
+}
+
+/// Subscribes to `topic` and applies the options to the resulting stream.
+pub(crate) fn subscribe<M>(
+    r2r_node: &SMutex<r2r::Node>,
+    topic: &str,
+    qos_profile: r2r::QosProfile,
+    options: &SubscriptionOptions<M>,
+) -> crate::Result<Subscribed<M>>
+where
+    M: Send + 'static + r2r::WrappedTypesupport,
+{
+    let mut node = r2r_node.lock_err("r2r_node")?;
+    let depth = qos_profile.depth;
+    let subscription = node.subscribe::<M>(topic, qos_profile)?.boxed();
+    let subscription = match options.intra_process {
+        None => subscription,
+        Some(subscribe_owned) => {
+            let topic = crate::intra::topic_name(&node.namespace()?, topic);
+            subscribe_owned(&topic, depth, subscription)
+        }
+    };
+    apply(&mut node, subscription, options)
+}
+
+/// Subscribes to `topic` and to its in-process publishers, sharing their messages,
+/// and applies the options to the resulting stream.
+pub(crate) fn subscribe_shared<M>(
+    r2r_node: &SMutex<r2r::Node>,
+    topic: &str,
+    qos_profile: r2r::QosProfile,
+    options: &SubscriptionOptions<M>,
+) -> crate::Result<Subscribed<Arc<M>>>
+where
+    M: Send + Sync + 'static + r2r::WrappedTypesupport,
+{
+    let mut node = r2r_node.lock_err("r2r_node")?;
+    let depth = qos_profile.depth;
+    let subscription = node.subscribe::<M>(topic, qos_profile)?;
+    let topic = crate::intra::topic_name(&node.namespace()?, topic);
+    let subscription = crate::intra::subscribe(&topic, depth, subscription);
+    apply(&mut node, subscription, options)
+}
+
+/// Counts, filters and throttles the messages of `subscription`.
+fn apply<M, T>(
+    node: &mut r2r::Node,
+    subscription: BoxStream<'static, T>,
+    options: &SubscriptionOptions<M>,
+) -> crate::Result<Subscribed<T>>
+where
+    M: 'static,
+    T: Borrow<M> + Send + 'static,
+{
+    let stats = options.stats.clone();
+    let subscription = subscription.inspect({
+        let stats = stats.clone();
+        move |_| stats.add_received()
+    });
+    let subscription = match options.filter.clone() {
+        None => subscription.boxed(),
+        Some(filter) => subscription
+            .filter(move |msg| futures::future::ready(filter(Borrow::<M>::borrow(msg))))
+            .boxed(),
+    };
+
+    let stream = match options.throttle {
+        None => subscription,
+        Some((max_hz, policy)) => {
+            let throttle = Throttle::new(max_hz, policy);
+            let timer = if policy == ThrottlePolicy::KeepLatest && !throttle.period().is_zero() {
+                Some(node.create_wall_timer(throttle.period())?)
+            } else {
+                None
+            };
+            crate::throttle::throttle_stream(subscription, throttle, timer, stats.clone()).boxed()
+        }
+    };
+    Ok(Subscribed {
+        stream,
+        policy: options.policy,
+        stats,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Messages handed to the callback when 5 messages arrive while it is busy, with the dropped count.
+    fn dispatched(policy: DeliveryPolicy) -> (Vec<u32>, u64) {
+        let stats = SubscriptionStats::new();
+        let subscribed = Subscribed {
+            stream: futures::stream::iter(1..=5).boxed(),
+            policy,
+            stats: stats.clone(),
+        };
+        let mut delivered = Vec::new();
+        futures::executor::block_on(subscribed.dispatch(|msg| {
+            delivered.push(msg);
+            futures::future::ready(())
+        }));
+        (delivered, stats.dropped())
+    }
+
+    #[test]
+    fn sequential_delivers_every_message() {
+        assert_eq!(
+            dispatched(DeliveryPolicy::Sequential),
+            (vec![1, 2, 3, 4, 5], 0)
+        );
+    }
+
+    #[test]
+    fn latest_only_delivers_the_last_message() {
+        assert_eq!(dispatched(DeliveryPolicy::LatestOnly), (vec![5], 4));
+    }
+
+    #[test]
+    fn bounded_drops_according_to_the_overflow() {
+        assert_eq!(
+            dispatched(DeliveryPolicy::Bounded(2, Overflow::DropOldest)),
+            (vec![4, 5], 3)
+        );
+        assert_eq!(
+            dispatched(DeliveryPolicy::Bounded(2, Overflow::DropNewest)),
+            (vec![1, 2], 3)
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use futures::stream::BoxStream;
+
+use crate::{MutexLockErr, MutexLockOrLog, SMutex};
+
+/// What the subscription knows about a message when taking it.
+///
+/// Not the rmw message info, which r2r does not expose: it has no source timestamp nor publisher gid.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReceiveInfo {
+    /// ROS time at which the message was taken from the subscription, after any wait in its queue.
+    pub taken_at: Duration,
+    /// Number of messages taken from the subscription before this one, gaps are not detected.
+    pub take_index: u64,
+}
+
+/// Subscribes to `topic`, each message comes with its `ReceiveInfo`.
+pub(crate) fn subscribe_with_info<M>(
+    r2r_node: &SMutex<r2r::Node>,
+    topic: &str,
+    qos_profile: r2r::QosProfile,
+) -> crate::Result<BoxStream<'static, (M, ReceiveInfo)>>
+where
+    M: Send + 'static + r2r::WrappedTypesupport,
+{
+    let (subscription, ros_clock) = {
+        let mut node = r2r_node.lock_err("r2r_node")?;
+        let subscription = node.subscribe::<M>(topic, qos_profile)?;
+        (subscription, node.get_ros_clock())
+    };
+
+    let mut take_index = 0;
+    let subscription = subscription.map(move |msg| {
+        let taken_at = ros_clock.lock_or_log("clock").get_now().unwrap_or_default();
+        let info = ReceiveInfo {
+            taken_at,
+            take_index,
+        };
+        take_index += 1;
+        (msg, info)
+    });
+    Ok(subscription.boxed())
+}
@@ -0,0 +1,222 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::{LayerContext, LayerSide, Layers, MutexLockOrLog, SMutex};
+
+type ErrorResponse<Res> = Arc<dyn Fn(String) -> Res + Send + Sync>;
+
+/// What a fallible service does when its handler returns an error, the error is logged in any case.
+#[derive(Clone, Default)]
+pub enum ServiceErrorPolicy<Res> {
+    /// Sends `Res::default()`.
+    #[default]
+    RespondDefault,
+    /// Sends the response built from the error message.
+    Respond(ErrorResponse<Res>),
+    /// Sends no response, the client waits until its own timeout.
+    Drop,
+}
+
+impl<Res> ServiceErrorPolicy<Res> {
+    pub fn respond<F>(error_response: F) -> Self
+    where
+        F: Fn(String) -> Res + Send + Sync + 'static,
+    {
+        Self::Respond(Arc::new(error_response))
+    }
+}
+
+impl<Res> std::fmt::Debug for ServiceErrorPolicy<Res> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RespondDefault => write!(f, "RespondDefault"),
+            Self::Respond(_) => write!(f, "Respond"),
+            Self::Drop => write!(f, "Drop"),
+        }
+    }
+}
+
+/// Service name, error policy and layers shared by the requests of a service.
+pub(crate) struct ServiceContext<S: r2r::WrappedServiceTypeSupport> {
+    r2r_node: SMutex<r2r::Node>,
+    service_name: String,
+    policy: ServiceErrorPolicy<S::Response>,
+    layers: Layers,
+}
+
+impl<S> ServiceContext<S>
+where
+    S: 'static + r2r::WrappedServiceTypeSupport,
+{
+    pub fn new(
+        r2r_node: SMutex<r2r::Node>,
+        service_name: &str,
+        policy: ServiceErrorPolicy<S::Response>,
+        layers: Layers,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            r2r_node,
+            service_name: service_name.to_string(),
+            policy,
+            layers,
+        })
+    }
+
+    fn context(&self) -> LayerContext<'_> {
+        LayerContext {
+            side: LayerSide::Service,
+            service_name: &self.service_name,
+        }
+    }
+
+    /// Request message for the handler, once through the layers.
+    pub fn request(
+        &self,
+        request: &r2r::ServiceRequest<S>,
+    ) -> std::result::Result<(S::Request, Instant), String> {
+        let mut message = request.message.clone();
+        let started = self.layers.request(&self.context(), &mut message)?;
+        Ok((message, started))
+    }
+
+    /// Passes the result of the handler through the layers.
+    pub fn response<E>(
+        &self,
+        result: std::result::Result<S::Response, E>,
+        started: Instant,
+    ) -> std::result::Result<S::Response, String>
+    where
+        E: std::fmt::Display,
+    {
+        match result {
+            Ok(mut response) => {
+                self.layers
+                    .response(&self.context(), &mut response, started);
+                Ok(response)
+            }
+            Err(e) => {
+                let e = e.to_string();
+                self.layers.error(&self.context(), &e);
+                Err(e)
+            }
+        }
+    }
+
+    /// Calls `handler` on the request message, once through the layers.
+    pub fn call<R>(
+        &self,
+        request: &r2r::ServiceRequest<S>,
+        handler: impl FnOnce(S::Request) -> R,
+    ) -> std::result::Result<(R, Instant), String> {
+        self.request(request)
+            .map(|(message, started)| (handler(message), started))
+    }
+
+    /// Awaits the handler called by `call` and responds to `request`.
+    pub async fn finish<R, E>(
+        self: Arc<Self>,
+        request: r2r::ServiceRequest<S>,
+        called: std::result::Result<(R, Instant), String>,
+    ) where
+        R: Future<Output = std::result::Result<S::Response, E>>,
+        E: std::fmt::Display,
+    {
+        let result = match called {
+            Ok((response, started)) => self.response(response.await, started),
+            Err(e) => Err(e),
+        };
+        self.respond(request, result);
+    }
+
+    /// Responds to `request`, errors are logged and handled according to the policy.
+    pub fn respond(
+        &self,
+        request: r2r::ServiceRequest<S>,
+        result: std::result::Result<S::Response, String>,
+    ) {
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                r2r::log_error!(
+                    self.r2r_node
+                        .lock_or_log("r2r_node.create_service")
+                        .logger(),
+                    "service handler error (service_name='{}'): {}",
+                    self.service_name,
+                    e
+                );
+                match &self.policy {
+                    ServiceErrorPolicy::RespondDefault => S::Response::default(),
+                    ServiceErrorPolicy::Respond(error_response) => error_response(e),
+                    ServiceErrorPolicy::Drop => return,
+                }
+            }
+        };
+        if let Err(e) = request.respond(response) {
+            r2r::log_error!(
+                self.r2r_node
+                    .lock_or_log("r2r_node.create_service")
+                    .logger(),
+                "service response error (service_name='{}'): {}",
+                self.service_name,
+                e
+            );
+        }
+    }
+}
+
+/// Answers a request of a deferred service, see `create_service_deferred_*`.
+///
+/// The response goes through the layers like the one of a handler.
+/// A responder dropped without answering fails the request.
+pub struct Responder<S>
+where
+    S: 'static + r2r::WrappedServiceTypeSupport,
+{
+    request: Option<r2r::ServiceRequest<S>>,
+    service: Arc<ServiceContext<S>>,
+    started: Instant,
+}
+
+impl<S> Responder<S>
+where
+    S: 'static + r2r::WrappedServiceTypeSupport,
+{
+    pub(crate) fn new(
+        request: r2r::ServiceRequest<S>,
+        service: Arc<ServiceContext<S>>,
+        started: Instant,
+    ) -> Self {
+        Self {
+            request: Some(request),
+            service,
+            started,
+        }
+    }
+
+    /// Sends `response` to the client.
+    pub fn respond(mut self, response: S::Response) {
+        self.complete(Ok::<_, String>(response));
+    }
+
+    /// Logs `error` and sends `S::Response::default()` to the client.
+    pub fn fail<E: std::fmt::Display>(mut self, error: E) {
+        self.complete(Err(error));
+    }
+
+    fn complete<E: std::fmt::Display>(&mut self, result: std::result::Result<S::Response, E>) {
+        if let Some(request) = self.request.take() {
+            let result = self.service.response(result, self.started);
+            self.service.respond(request, result);
+        }
+    }
+}
+
+impl<S> Drop for Responder<S>
+where
+    S: 'static + r2r::WrappedServiceTypeSupport,
+{
+    fn drop(&mut self) {
+        self.complete(Err("responder dropped without responding"));
+    }
+}
@@ -0,0 +1,33 @@
+use futures::{Future, FutureExt};
+
+/// Runs `future` on `pool`, the returned future completes with it.
+/// `context` names the task in the logged errors, e.g. "service request".
+pub(crate) fn spawn_pooled<R>(
+    pool: &futures::executor::ThreadPool,
+    context: &'static str,
+    future: R,
+) -> impl Future<Output = ()> + use<R>
+where
+    R: Future<Output = ()> + Send + 'static,
+{
+    use futures::task::SpawnExt;
+    match pool.spawn_with_handle(future) {
+        Ok(handle) => handle.left_future(),
+        Err(e) => {
+            r2r::log_error!("", "{} spawn error: {}", context, e);
+            futures::future::ready(()).right_future()
+        }
+    }
+}
+
+/// Runs `future` on the tokio runtime, the returned future completes with it.
+pub(crate) fn spawn_tokio<R>(context: &'static str, future: R) -> impl Future<Output = ()> + use<R>
+where
+    R: Future<Output = ()> + Send + 'static,
+{
+    tokio::task::spawn(future).map(move |result| {
+        if let Err(e) = result {
+            r2r::log_error!("", "{} error: {}", context, e);
+        }
+    })
+}
@@ -0,0 +1,47 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::StreamExt;
+use futures::stream::BoxStream;
+
+use crate::{MutexLockErr, SMutex};
+
+/// Typed stream of the messages received on a topic.
+///
+/// The stream keeps the node alive, messages arrive while the node spins.
+pub struct Subscription<M> {
+    stream: BoxStream<'static, M>,
+    _r2r_node: SMutex<r2r::Node>,
+}
+
+impl<M> Subscription<M>
+where
+    M: Send + 'static + r2r::WrappedTypesupport,
+{
+    pub(crate) fn create(
+        r2r_node: &SMutex<r2r::Node>,
+        topic: &str,
+        qos_profile: r2r::QosProfile,
+    ) -> crate::Result<Self> {
+        let stream = {
+            let mut node = r2r_node.lock_err("r2r_node")?;
+            node.subscribe::<M>(topic, qos_profile)?
+        };
+        Ok(Self {
+            stream: stream.boxed(),
+            _r2r_node: r2r_node.clone(),
+        })
+    }
+}
+
+impl<M> futures::Stream for Subscription<M> {
+    type Item = M;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<M>> {
+        self.stream.poll_next_unpin(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,365 @@
+use std::any::Any;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures::StreamExt;
+use futures::stream::BoxStream;
+
+use crate::{MutexLockErr, SMutex};
+
+/// Message carrying a `std_msgs/Header` stamp, see `impl_stamped!` for other message types.
+pub trait Stamped {
+    fn stamp(&self) -> &r2r::builtin_interfaces::msg::Time;
+}
+
+impl Stamped for r2r::std_msgs::msg::Header {
+    fn stamp(&self) -> &r2r::builtin_interfaces::msg::Time {
+        &self.stamp
+    }
+}
+
+#[cfg(feature = "geometry_msgs")]
+crate::impl_stamped!(
+    r2r::geometry_msgs::msg::AccelStamped,
+    r2r::geometry_msgs::msg::PointStamped,
+    r2r::geometry_msgs::msg::PoseStamped,
+    r2r::geometry_msgs::msg::PoseWithCovarianceStamped,
+    r2r::geometry_msgs::msg::TransformStamped,
+    r2r::geometry_msgs::msg::TwistStamped,
+    r2r::geometry_msgs::msg::WrenchStamped,
+);
+
+#[cfg(feature = "nav_msgs")]
+crate::impl_stamped!(r2r::nav_msgs::msg::Odometry);
+
+#[cfg(feature = "sensor_msgs")]
+crate::impl_stamped!(
+    r2r::sensor_msgs::msg::CameraInfo,
+    r2r::sensor_msgs::msg::CompressedImage,
+    r2r::sensor_msgs::msg::Image,
+    r2r::sensor_msgs::msg::Imu,
+    r2r::sensor_msgs::msg::LaserScan,
+    r2r::sensor_msgs::msg::PointCloud2,
+);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyncPolicy {
+    /// Messages are matched when all their stamps are equal.
+    ExactTime { queue_size: usize },
+    /// Messages are matched when the stamps at the head of the queues are at most `slop` apart,
+    /// a head too old to be matched is dropped.
+    ///
+    /// Simpler than the ApproximateTime policy of `message_filters`, which searches the best set
+    /// over the whole queues.
+    SlopTime { queue_size: usize, slop: Duration },
+}
+
+impl SyncPolicy {
+    fn queue_size(&self) -> usize {
+        match self {
+            SyncPolicy::ExactTime { queue_size } => *queue_size,
+            SyncPolicy::SlopTime { queue_size, .. } => *queue_size,
+        }
+    }
+
+    fn slop(&self) -> i64 {
+        match self {
+            SyncPolicy::ExactTime { .. } => 0,
+            SyncPolicy::SlopTime { slop, .. } => slop.as_nanos() as i64,
+        }
+    }
+}
+
+//-------------------------------------------------- Queues --------------------------------------------------
+
+type Entry = (i64, Box<dyn Any + Send>);
+type Set = Vec<Box<dyn Any + Send>>;
+
+/// One queue per topic, messages of a topic are expected in stamp order.
+struct SyncQueues {
+    queues: Vec<VecDeque<Entry>>,
+    queue_size: usize,
+    slop: i64,
+}
+
+impl SyncQueues {
+    fn new(count: usize, policy: SyncPolicy) -> Self {
+        Self {
+            queues: (0..count).map(|_| VecDeque::new()).collect(),
+            queue_size: policy.queue_size().max(1),
+            slop: policy.slop(),
+        }
+    }
+
+    /// Returns the sets of messages matched thanks to the new message.
+    fn push(&mut self, index: usize, stamp: i64, msg: Box<dyn Any + Send>) -> Vec<Set> {
+        let queue = &mut self.queues[index];
+        queue.push_back((stamp, msg));
+        if queue.len() > self.queue_size {
+            queue.pop_front();
+        }
+
+        let mut sets = Vec::new();
+        while self.queues.iter().all(|queue| !queue.is_empty()) {
+            let (oldest, min) = self
+                .queues
+                .iter()
+                .map(|queue| queue[0].0)
+                .enumerate()
+                .min_by_key(|(_, stamp)| *stamp)
+                .unwrap_or_default();
+            let max = self
+                .queues
+                .iter()
+                .map(|queue| queue[0].0)
+                .max()
+                .unwrap_or(min);
+            if max - min <= self.slop {
+                let set = self
+                    .queues
+                    .iter_mut()
+                    .filter_map(|queue| queue.pop_front())
+                    .map(|(_, msg)| msg)
+                    .collect();
+                sets.push(set);
+            } else {
+                // the oldest head can not be matched anymore
+                self.queues[oldest].pop_front();
+            }
+        }
+        sets
+    }
+}
+
+fn nanos(time: &r2r::builtin_interfaces::msg::Time) -> i64 {
+    time.sec as i64 * 1_000_000_000 + time.nanosec as i64
+}
+
+type Tagged = (usize, i64, Box<dyn Any + Send>);
+
+fn tag<M, S>(stream: S, index: usize) -> BoxStream<'static, Tagged>
+where
+    M: Stamped + Send + 'static,
+    S: futures::Stream<Item = M> + Send + 'static,
+{
+    stream
+        .map(move |msg| {
+            let stamp = nanos(msg.stamp());
+            (index, stamp, Box::new(msg) as Box<dyn Any + Send>)
+        })
+        .boxed()
+}
+
+fn synchronize(
+    streams: Vec<BoxStream<'static, Tagged>>,
+    policy: SyncPolicy,
+) -> impl futures::Stream<Item = std::vec::IntoIter<Box<dyn Any + Send>>> + Send + 'static {
+    let queues = SyncQueues::new(streams.len(), policy);
+    futures::stream::select_all(streams)
+        .scan(queues, |queues, (index, stamp, msg)| {
+            futures::future::ready(Some(queues.push(index, stamp, msg)))
+        })
+        .flat_map(futures::stream::iter)
+        .map(Vec::into_iter)
+}
+
+fn take<M: 'static>(set: &mut std::vec::IntoIter<Box<dyn Any + Send>>) -> Option<M> {
+    set.next()?.downcast::<M>().ok().map(|msg| *msg)
+}
+
+//-------------------------------------------------- Synchronized --------------------------------------------------
+
+type Synchronized<T> = crate::Result<BoxStream<'static, T>>;
+
+/// Stream of the matched messages of 2 topics.
+pub(crate) fn synchronize_2<M1, M2>(
+    r2r_node: &SMutex<r2r::Node>,
+    topics: [&str; 2],
+    qos_profile: r2r::QosProfile,
+    policy: SyncPolicy,
+) -> Synchronized<(M1, M2)>
+where
+    M1: Stamped + Send + 'static + r2r::WrappedTypesupport,
+    M2: Stamped + Send + 'static + r2r::WrappedTypesupport,
+{
+    let streams = {
+        let mut node = r2r_node.lock_err("r2r_node")?;
+        vec![
+            tag(node.subscribe::<M1>(topics[0], qos_profile.clone())?, 0),
+            tag(node.subscribe::<M2>(topics[1], qos_profile.clone())?, 1),
+        ]
+    };
+    Ok(synchronize(streams, policy)
+        .map(|mut set| -> Option<_> { Some((take(&mut set)?, take(&mut set)?)) })
+        .filter_map(futures::future::ready)
+        .boxed())
+}
+
+/// Stream of the matched messages of 3 topics.
+pub(crate) fn synchronize_3<M1, M2, M3>(
+    r2r_node: &SMutex<r2r::Node>,
+    topics: [&str; 3],
+    qos_profile: r2r::QosProfile,
+    policy: SyncPolicy,
+) -> Synchronized<(M1, M2, M3)>
+where
+    M1: Stamped + Send + 'static + r2r::WrappedTypesupport,
+    M2: Stamped + Send + 'static + r2r::WrappedTypesupport,
+    M3: Stamped + Send + 'static + r2r::WrappedTypesupport,
+{
+    let streams = {
+        let mut node = r2r_node.lock_err("r2r_node")?;
+        vec![
+            tag(node.subscribe::<M1>(topics[0], qos_profile.clone())?, 0),
+            tag(node.subscribe::<M2>(topics[1], qos_profile.clone())?, 1),
+            tag(node.subscribe::<M3>(topics[2], qos_profile.clone())?, 2),
+        ]
+    };
+    Ok(synchronize(streams, policy)
+        .map(|mut set| -> Option<_> { Some((take(&mut set)?, take(&mut set)?, take(&mut set)?)) })
+        .filter_map(futures::future::ready)
+        .boxed())
+}
+
+/// Stream of the matched messages of 4 topics.
+pub(crate) fn synchronize_4<M1, M2, M3, M4>(
+    r2r_node: &SMutex<r2r::Node>,
+    topics: [&str; 4],
+    qos_profile: r2r::QosProfile,
+    policy: SyncPolicy,
+) -> Synchronized<(M1, M2, M3, M4)>
+where
+    M1: Stamped + Send + 'static + r2r::WrappedTypesupport,
+    M2: Stamped + Send + 'static + r2r::WrappedTypesupport,
+    M3: Stamped + Send + 'static + r2r::WrappedTypesupport,
+    M4: Stamped + Send + 'static + r2r::WrappedTypesupport,
+{
+    let streams = {
+        let mut node = r2r_node.lock_err("r2r_node")?;
+        vec![
+            tag(node.subscribe::<M1>(topics[0], qos_profile.clone())?, 0),
+            tag(node.subscribe::<M2>(topics[1], qos_profile.clone())?, 1),
+            tag(node.subscribe::<M3>(topics[2], qos_profile.clone())?, 2),
+            tag(node.subscribe::<M4>(topics[3], qos_profile.clone())?, 3),
+        ]
+    };
+    Ok(synchronize(streams, policy)
+        .map(|mut set| -> Option<_> {
+            Some((
+                take(&mut set)?,
+                take(&mut set)?,
+                take(&mut set)?,
+                take(&mut set)?,
+            ))
+        })
+        .filter_map(futures::future::ready)
+        .boxed())
+}
+
+/// Stream of the matched messages of 5 topics.
+pub(crate) fn synchronize_5<M1, M2, M3, M4, M5>(
+    r2r_node: &SMutex<r2r::Node>,
+    topics: [&str; 5],
+    qos_profile: r2r::QosProfile,
+    policy: SyncPolicy,
+) -> Synchronized<(M1, M2, M3, M4, M5)>
+where
+    M1: Stamped + Send + 'static + r2r::WrappedTypesupport,
+    M2: Stamped + Send + 'static + r2r::WrappedTypesupport,
+    M3: Stamped + Send + 'static + r2r::WrappedTypesupport,
+    M4: Stamped + Send + 'static + r2r::WrappedTypesupport,
+    M5: Stamped + Send + 'static + r2r::WrappedTypesupport,
+{
+    let streams = {
+        let mut node = r2r_node.lock_err("r2r_node")?;
+        vec![
+            tag(node.subscribe::<M1>(topics[0], qos_profile.clone())?, 0),
+            tag(node.subscribe::<M2>(topics[1], qos_profile.clone())?, 1),
+            tag(node.subscribe::<M3>(topics[2], qos_profile.clone())?, 2),
+            tag(node.subscribe::<M4>(topics[3], qos_profile.clone())?, 3),
+            tag(node.subscribe::<M5>(topics[4], qos_profile.clone())?, 4),
+        ]
+    };
+    Ok(synchronize(streams, policy)
+        .map(|mut set| -> Option<_> {
+            Some((
+                take(&mut set)?,
+                take(&mut set)?,
+                take(&mut set)?,
+                take(&mut set)?,
+                take(&mut set)?,
+            ))
+        })
+        .filter_map(futures::future::ready)
+        .boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: i64 = 1_000_000;
+
+    fn stamps(sets: Vec<Set>) -> Vec<Vec<i64>> {
+        sets.into_iter()
+            .map(|set| {
+                set.into_iter()
+                    .map(|msg| *msg.downcast::<i64>().unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn push(queues: &mut SyncQueues, index: usize, stamp: i64) -> Vec<Vec<i64>> {
+        stamps(queues.push(index, stamp, Box::new(stamp)))
+    }
+
+    #[test]
+    fn exact_time_matches_equal_stamps() {
+        let mut queues = SyncQueues::new(2, SyncPolicy::ExactTime { queue_size: 10 });
+        assert!(push(&mut queues, 0, 10 * MS).is_empty());
+        assert!(push(&mut queues, 1, 5 * MS).is_empty());
+        assert_eq!(push(&mut queues, 1, 10 * MS), vec![vec![10 * MS, 10 * MS]]);
+    }
+
+    #[test]
+    fn slop_time_matches_interleaved_rates() {
+        let policy = SyncPolicy::SlopTime {
+            queue_size: 10,
+            slop: Duration::from_millis(5),
+        };
+        let mut queues = SyncQueues::new(2, policy);
+        // topic 0 at 100 Hz, topic 1 at 30 Hz shifted by 2 ms, received interleaved by stamp
+        let mut events: Vec<(i64, usize)> = (0..20).map(|i| (i * 10 * MS, 0)).collect();
+        events.extend((0..7).map(|i| (i * 33 * MS + 2 * MS, 1)));
+        events.sort();
+        let mut sets = Vec::new();
+        for (stamp, index) in events {
+            sets.extend(push(&mut queues, index, stamp));
+        }
+        for set in &sets {
+            assert!(
+                (set[0] - set[1]).abs() <= 5 * MS,
+                "{set:?} apart more than the slop"
+            );
+        }
+        let matched: Vec<i64> = sets.iter().map(|set| set[1]).collect();
+        assert_eq!(
+            matched,
+            vec![2 * MS, 35 * MS, 68 * MS, 101 * MS, 134 * MS, 167 * MS]
+        );
+        for pair in sets.windows(2) {
+            assert!(pair[0][0] < pair[1][0], "sets out of order");
+        }
+    }
+
+    #[test]
+    fn queue_size_bounds_the_unmatched_messages() {
+        let mut queues = SyncQueues::new(2, SyncPolicy::ExactTime { queue_size: 2 });
+        for stamp in 1..=5 {
+            assert!(push(&mut queues, 0, stamp * MS).is_empty());
+        }
+        assert_eq!(queues.queues[0].len(), 2);
+        assert_eq!(push(&mut queues, 1, 5 * MS), vec![vec![5 * MS, 5 * MS]]);
+    }
+}
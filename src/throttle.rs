@@ -0,0 +1,286 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use futures::future::{Either, select};
+
+use crate::{MutexLockOrLog, SMutex};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThrottlePolicy {
+    /// Messages arriving before the end of the period are dropped.
+    #[default]
+    Drop,
+    /// The latest message arriving before the end of the period is kept and delivered once the period is over.
+    ///
+    /// The kept message is flushed by a timer of the same period that is not aligned with the deliveries,
+    /// so it can wait up to almost two periods.
+    KeepLatest,
+}
+
+/// Rate limiter shared by throttled publishers and subscriptions.
+pub struct Throttle<M> {
+    period: Duration,
+    policy: ThrottlePolicy,
+    last: Option<Instant>,
+    pending: Option<M>,
+    dropped: u64,
+}
+
+impl<M> Throttle<M> {
+    /// A `max_hz` that is not strictly positive disables throttling.
+    pub fn new(max_hz: f64, policy: ThrottlePolicy) -> Self {
+        let period = if max_hz > 0.0 && max_hz.is_finite() {
+            Duration::from_secs_f64(1.0 / max_hz)
+        } else {
+            Duration::ZERO
+        };
+        Self {
+            period,
+            policy,
+            last: None,
+            pending: None,
+            dropped: 0,
+        }
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    pub fn policy(&self) -> ThrottlePolicy {
+        self.policy
+    }
+
+    /// Number of messages dropped or replaced by a newer one so far.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Returns the message if it can be delivered now, otherwise drops or keeps it according to the policy.
+    pub fn offer(&mut self, msg: M) -> Option<M> {
+        self.offer_at(msg, Instant::now())
+    }
+
+    fn offer_at(&mut self, msg: M, now: Instant) -> Option<M> {
+        if self.open(now) {
+            self.supersede();
+            Some(msg)
+        } else {
+            self.keep(msg);
+            None
+        }
+    }
+
+    /// Returns the kept message once the period is over.
+    pub fn flush(&mut self) -> Option<M> {
+        self.flush_at(Instant::now())
+    }
+
+    fn flush_at(&mut self, now: Instant) -> Option<M> {
+        if self.pending.is_some() && self.open(now) {
+            self.pending.take()
+        } else {
+            None
+        }
+    }
+
+    fn keep(&mut self, msg: M) {
+        match self.policy {
+            ThrottlePolicy::Drop => self.dropped += 1,
+            ThrottlePolicy::KeepLatest => {
+                if self.pending.replace(msg).is_some() {
+                    self.dropped += 1;
+                }
+            }
+        }
+    }
+
+    fn open(&mut self, now: Instant) -> bool {
+        let open = match self.last {
+            Some(last) => now.duration_since(last) >= self.period,
+            None => true,
+        };
+        if open {
+            self.last = Some(now);
+        }
+        open
+    }
+
+    /// A message delivered now supersedes the kept one.
+    fn supersede(&mut self) {
+        if self.pending.take().is_some() {
+            self.dropped += 1;
+        }
+    }
+}
+
+impl<M: Clone> Throttle<M> {
+    /// Same as `offer` for a borrowed message, the message is only cloned when it has to be kept.
+    pub fn admit(&mut self, msg: &M) -> bool {
+        self.admit_at(msg, Instant::now())
+    }
+
+    fn admit_at(&mut self, msg: &M, now: Instant) -> bool {
+        if self.open(now) {
+            self.supersede();
+            true
+        } else {
+            match self.policy {
+                ThrottlePolicy::Drop => self.dropped += 1,
+                ThrottlePolicy::KeepLatest => self.keep(msg.clone()),
+            }
+            false
+        }
+    }
+}
+
+//-------------------------------------------------- Stream --------------------------------------------------
+
+enum Event<M> {
+    Message(Option<M>),
+    Tick,
+}
+
+/// Throttles a subscription stream, the timer is only needed by the `KeepLatest` policy.
+pub(crate) fn throttle_stream<M, S>(
+    stream: S,
+    throttle: Throttle<M>,
+    timer: Option<r2r::Timer>,
+    stats: crate::SubscriptionStats,
+) -> impl futures::Stream<Item = M>
+where
+    S: futures::Stream<Item = M> + Unpin,
+{
+    futures::stream::unfold(
+        (stream, throttle, timer, stats),
+        |(mut stream, mut throttle, mut timer, stats)| async move {
+            loop {
+                let event = match timer.as_mut() {
+                    None => Event::Message(stream.next().await),
+                    Some(timer) => {
+                        let tick = std::pin::pin!(timer.tick());
+                        match select(stream.next(), tick).await {
+                            Either::Left((msg, _)) => Event::Message(msg),
+                            Either::Right(_) => Event::Tick,
+                        }
+                    }
+                };
+                let dropped = throttle.dropped();
+                let msg = match event {
+                    Event::Message(Some(msg)) => throttle.offer(msg),
+                    Event::Message(None) => return None,
+                    Event::Tick => throttle.flush(),
+                };
+                stats.add_dropped(throttle.dropped() - dropped);
+                if let Some(msg) = msg {
+                    return Some((msg, (stream, throttle, timer, stats)));
+                }
+            }
+        },
+    )
+}
+
+//-------------------------------------------------- Publisher --------------------------------------------------
+
+/// Publishes the message kept by a `KeepLatest` publisher throttle once its period is over.
+pub(crate) async fn flush_publisher<M>(
+    mut timer: r2r::Timer,
+    throttle: SMutex<Throttle<M>>,
+    r2r_publisher: SMutex<r2r::Publisher<M>>,
+    intra_process: Option<Arc<crate::intra::IntraPublisher>>,
+    logger: String,
+) where
+    M: r2r::WrappedTypesupport + 'static,
+{
+    loop {
+        if let Err(e) = timer.tick().await {
+            r2r::log_error!(&logger, "throttle timer execution error: {}", e);
+            continue;
+        }
+        let pending = throttle.lock_or_log("throttle").flush();
+        let Some(msg) = pending else {
+            continue;
+        };
+        if let Some(intra_process) = &intra_process {
+            intra_process.deliver(|| msg.clone());
+        }
+        if let Err(e) = r2r_publisher.lock_or_log("r2r_publisher").publish(&msg) {
+            r2r::log_error!(&logger, "{}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 10 Hz, a period of 100 ms.
+    fn throttle(policy: ThrottlePolicy) -> (Throttle<u32>, Instant) {
+        (Throttle::new(10.0, policy), Instant::now())
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn drop_policy_drops_the_messages_within_the_period() {
+        let (mut throttle, start) = throttle(ThrottlePolicy::Drop);
+        assert_eq!(throttle.offer_at(1, start), Some(1));
+        assert_eq!(throttle.offer_at(2, start + ms(50)), None);
+        assert_eq!(throttle.offer_at(3, start + ms(99)), None);
+        assert_eq!(throttle.flush_at(start + ms(150)), None);
+        assert_eq!(throttle.dropped(), 2);
+    }
+
+    #[test]
+    fn period_boundary_opens_the_throttle() {
+        let (mut throttle, start) = throttle(ThrottlePolicy::Drop);
+        assert_eq!(throttle.offer_at(1, start), Some(1));
+        assert_eq!(throttle.offer_at(2, start + ms(100)), Some(2));
+        // the period restarts at the last delivery
+        assert_eq!(throttle.offer_at(3, start + ms(150)), None);
+        assert_eq!(throttle.offer_at(4, start + ms(200)), Some(4));
+    }
+
+    #[test]
+    fn keep_latest_releases_the_kept_message_after_the_period() {
+        let (mut throttle, start) = throttle(ThrottlePolicy::KeepLatest);
+        assert_eq!(throttle.offer_at(1, start), Some(1));
+        assert_eq!(throttle.offer_at(2, start + ms(30)), None);
+        assert_eq!(throttle.flush_at(start + ms(60)), None);
+        assert_eq!(throttle.flush_at(start + ms(100)), Some(2));
+        assert_eq!(throttle.flush_at(start + ms(300)), None);
+        assert_eq!(throttle.dropped(), 0);
+    }
+
+    #[test]
+    fn kept_message_is_replaced_by_a_newer_one() {
+        let (mut throttle, start) = throttle(ThrottlePolicy::KeepLatest);
+        assert!(throttle.admit_at(&1, start));
+        assert!(!throttle.admit_at(&2, start + ms(30)));
+        assert!(!throttle.admit_at(&3, start + ms(60)));
+        assert_eq!(throttle.dropped(), 1);
+        assert_eq!(throttle.flush_at(start + ms(100)), Some(3));
+    }
+
+    #[test]
+    fn message_delivered_after_the_period_supersedes_the_kept_one() {
+        let (mut throttle, start) = throttle(ThrottlePolicy::KeepLatest);
+        assert_eq!(throttle.offer_at(1, start), Some(1));
+        assert_eq!(throttle.offer_at(2, start + ms(30)), None);
+        assert_eq!(throttle.offer_at(3, start + ms(120)), Some(3));
+        assert_eq!(throttle.flush_at(start + ms(250)), None);
+        assert_eq!(throttle.dropped(), 1);
+    }
+
+    #[test]
+    fn zero_rate_disables_throttling() {
+        let mut throttle = Throttle::new(0.0, ThrottlePolicy::Drop);
+        let start = Instant::now();
+        assert_eq!(throttle.offer_at(1, start), Some(1));
+        assert_eq!(throttle.offer_at(2, start), Some(2));
+        assert_eq!(throttle.dropped(), 0);
+    }
+}
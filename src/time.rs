@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::{MutexCreate, MutexLockOrLog, SMutex};
+
+/// Clock read by `now_with`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ClockType {
+    /// Clock of `now`, following `/clock` when the node uses the simulation time.
+    #[default]
+    Ros,
+    System,
+    Steady,
+}
+
+impl From<ClockType> for r2r::ClockType {
+    fn from(clock_type: ClockType) -> Self {
+        match clock_type {
+            ClockType::Ros => r2r::ClockType::RosTime,
+            ClockType::System => r2r::ClockType::SystemTime,
+            ClockType::Steady => r2r::ClockType::SteadyTime,
+        }
+    }
+}
+
+impl From<r2r::ClockType> for ClockType {
+    fn from(clock_type: r2r::ClockType) -> Self {
+        match clock_type {
+            r2r::ClockType::RosTime => ClockType::Ros,
+            r2r::ClockType::SystemTime => ClockType::System,
+            r2r::ClockType::SteadyTime => ClockType::Steady,
+        }
+    }
+}
+
+/// Point in time of a clock, as the duration since its epoch.
+///
+/// Adding or subtracting a `Duration` saturates.
+/// Times of different clocks are never equal and do not compare, `duration_since` gives their difference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Time {
+    since_epoch: Duration,
+    clock_type: ClockType,
+}
+
+impl Time {
+    pub fn new(since_epoch: Duration, clock_type: ClockType) -> Self {
+        Self {
+            since_epoch,
+            clock_type,
+        }
+    }
+
+    pub fn since_epoch(&self) -> Duration {
+        self.since_epoch
+    }
+
+    pub fn clock_type(&self) -> ClockType {
+        self.clock_type
+    }
+
+    /// Duration from `earlier` to `self`, zero when `earlier` is later,
+    /// `None` when the times are of different clocks.
+    pub fn duration_since(&self, earlier: Time) -> Option<Duration> {
+        (self.clock_type == earlier.clock_type)
+            .then(|| self.since_epoch.saturating_sub(earlier.since_epoch))
+    }
+
+    /// Header stamped with this time.
+    pub fn to_header(&self, frame_id: &str) -> r2r::std_msgs::msg::Header {
+        r2r::std_msgs::msg::Header {
+            stamp: (*self).into(),
+            frame_id: frame_id.to_string(),
+        }
+    }
+}
+
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Time) -> Option<Ordering> {
+        (self.clock_type == other.clock_type).then(|| self.since_epoch.cmp(&other.since_epoch))
+    }
+}
+
+impl Add<Duration> for Time {
+    type Output = Time;
+
+    fn add(self, duration: Duration) -> Time {
+        Time::new(self.since_epoch.saturating_add(duration), self.clock_type)
+    }
+}
+
+impl AddAssign<Duration> for Time {
+    fn add_assign(&mut self, duration: Duration) {
+        self.since_epoch = self.since_epoch.saturating_add(duration);
+    }
+}
+
+impl Sub<Duration> for Time {
+    type Output = Time;
+
+    fn sub(self, duration: Duration) -> Time {
+        Time::new(self.since_epoch.saturating_sub(duration), self.clock_type)
+    }
+}
+
+impl SubAssign<Duration> for Time {
+    fn sub_assign(&mut self, duration: Duration) {
+        self.since_epoch = self.since_epoch.saturating_sub(duration);
+    }
+}
+
+/// Message stamps are ROS times, negative ones are clamped to the epoch.
+impl From<r2r::builtin_interfaces::msg::Time> for Time {
+    fn from(stamp: r2r::builtin_interfaces::msg::Time) -> Self {
+        let since_epoch = match u64::try_from(stamp.sec) {
+            Ok(sec) => Duration::new(sec, 0) + Duration::from_nanos(stamp.nanosec as u64),
+            Err(_) => Duration::ZERO,
+        };
+        Time::new(since_epoch, ClockType::Ros)
+    }
+}
+
+impl From<Time> for r2r::builtin_interfaces::msg::Time {
+    fn from(time: Time) -> Self {
+        r2r::Clock::to_builtin_time(&time.since_epoch)
+    }
+}
+
+impl From<&r2r::std_msgs::msg::Header> for Time {
+    fn from(header: &r2r::std_msgs::msg::Header) -> Self {
+        header.stamp.clone().into()
+    }
+}
+
+/// System or steady clock, created once for the process.
+fn shared_clock(clock_type: ClockType) -> &'static r2r::Result<SMutex<r2r::Clock>> {
+    static SYSTEM: OnceLock<r2r::Result<SMutex<r2r::Clock>>> = OnceLock::new();
+    static STEADY: OnceLock<r2r::Result<SMutex<r2r::Clock>>> = OnceLock::new();
+    let clock = match clock_type {
+        ClockType::Steady => &STEADY,
+        _ => &SYSTEM,
+    };
+    clock.get_or_init(|| r2r::Clock::create(clock_type.into()).map(SMutex::create))
+}
+
+/// Current time of `clock_type`, the ROS clock being the one of the node.
+pub(crate) fn now_with(r2r_node: &SMutex<r2r::Node>, clock_type: ClockType) -> Time {
+    let now = match clock_type {
+        ClockType::Ros => {
+            let ros_clock = r2r_node.lock_or_log("r2r_node").get_ros_clock();
+            let mut clock = ros_clock.lock_or_log("clock");
+            clock.get_now()
+        }
+        _ => match shared_clock(clock_type) {
+            Ok(clock) => clock.lock_or_log("clock").get_now(),
+            Err(e) => Err(e.clone()),
+        },
+    };
+    let since_epoch = now.unwrap_or_else(|e| {
+        eprintln!("[WARN] get_now() error: {e}");
+        Duration::from_secs(0)
+    });
+    Time::new(since_epoch, clock_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(millis: u64, clock_type: ClockType) -> Time {
+        Time::new(Duration::from_millis(millis), clock_type)
+    }
+
+    #[test]
+    fn times_of_one_clock_compare_and_subtract() {
+        let earlier = time(100, ClockType::Steady);
+        let later = time(250, ClockType::Steady);
+        assert!(earlier < later);
+        assert_eq!(
+            later.duration_since(earlier),
+            Some(Duration::from_millis(150))
+        );
+        assert_eq!(earlier.duration_since(later), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn times_of_different_clocks_do_not_compare() {
+        let ros = time(100, ClockType::Ros);
+        let system = time(100, ClockType::System);
+        assert_ne!(ros, system);
+        assert_eq!(ros.partial_cmp(&system), None);
+        assert_eq!(ros.duration_since(system), None);
+    }
+
+    #[test]
+    fn adding_and_subtracting_durations_saturates() {
+        let mut time = time(100, ClockType::Steady);
+        assert_eq!(
+            (time - Duration::from_secs(1)).since_epoch(),
+            Duration::ZERO
+        );
+        time += Duration::MAX;
+        assert_eq!(time.since_epoch(), Duration::MAX);
+        assert_eq!(time + Duration::from_secs(1), time);
+    }
+}
@@ -9,6 +9,9 @@ where
     Defined {
         logger: String,
         r2r_publisher: crate::SMutex<r2r::Publisher<M>>,
+        throttle: Option<crate::SMutex<crate::Throttle<M>>>,
+        /// Also hands the messages to the in-process subscriptions.
+        intra_process: Option<std::sync::Arc<crate::intra::IntraPublisher>>,
     },
 }
 
@@ -33,6 +36,59 @@ where
             Publisher::Defined {
                 logger,
                 r2r_publisher,
+                throttle,
+                intra_process,
+            } => {
+                if let Some(throttle) = throttle
+                    && !throttle.lock_or_log("throttle").admit(msg)
+                {
+                    return;
+                }
+                if let Some(intra_process) = intra_process {
+                    intra_process.deliver(|| msg.clone());
+                }
+                let r2r_publisher = r2r_publisher.lock_or_log("r2r_publisher");
+                if let Err(e) = r2r_publisher.publish(msg) {
+                    r2r::log_error!(logger, "{}", e);
+                }
+            }
+        }
+    }
+
+    /// Borrows a message to fill in place, see `Loaned`.
+    pub fn borrow_loaned(&self) -> crate::Result<crate::Loaned<M>> {
+        match self {
+            Publisher::Empty => Err("publisher not initialized".to_string().into()),
+            Publisher::Defined {
+                logger,
+                r2r_publisher,
+                intra_process,
+                ..
+            } => crate::Loaned::borrow(r2r_publisher, intra_process.clone(), logger),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub enum UntypedPublisher {
+    #[default]
+    Empty,
+    Defined {
+        logger: String,
+        r2r_publisher: crate::SMutex<r2r::PublisherUntyped>,
+    },
+}
+
+impl UntypedPublisher {
+    /// Publishes a message given as JSON, the type is the one given at creation.
+    pub fn publish(&self, msg: serde_json::Value) {
+        match self {
+            UntypedPublisher::Empty => {
+                r2r::log_error!("", "publisher not initialized");
+            }
+            UntypedPublisher::Defined {
+                logger,
+                r2r_publisher,
             } => {
                 if let Err(e) = r2r_publisher.lock_or_log("r2r_publisher").publish(msg) {
                     r2r::log_error!(logger, "{}", e);
@@ -40,4 +96,21 @@ where
             }
         }
     }
+
+    /// Publishes an already serialized (CDR) message, e.g. the data of a `RawMessage`.
+    pub fn publish_raw(&self, data: &[u8]) {
+        match self {
+            UntypedPublisher::Empty => {
+                r2r::log_error!("", "publisher not initialized");
+            }
+            UntypedPublisher::Defined {
+                logger,
+                r2r_publisher,
+            } => {
+                if let Err(e) = r2r_publisher.lock_or_log("r2r_publisher").publish_raw(data) {
+                    r2r::log_error!(logger, "{}", e);
+                }
+            }
+        }
+    }
 }
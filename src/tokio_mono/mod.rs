@@ -1,4 +1,4 @@
 pub mod node;
 pub use node::*;
 
-pub use crate::tokio::{Client, Publisher, TMutex};
+pub use crate::tokio::{Client, Publisher, TMutex, UntypedPublisher};
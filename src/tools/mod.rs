@@ -0,0 +1,80 @@
+//! Generic topic tools (relay, transform, throttle, mux) working on any node type.
+//!
+//! There is one module per node trait: `node_async`, `node_mono` and `node_multi`.
+
+pub mod node_async;
+pub mod node_mono;
+pub mod node_multi;
+
+use crate::{MutexCreate, MutexLockErr, MutexLockOrLog, SMutex};
+
+/// Selects which input of a mux is forwarded to its output, cheap to clone.
+#[derive(Clone, Debug)]
+pub struct Mux {
+    inputs: Vec<String>,
+    selected: SMutex<Option<usize>>,
+}
+
+impl Mux {
+    /// The first input is selected.
+    pub fn new(inputs: &[&str]) -> Self {
+        let selected = if inputs.is_empty() { None } else { Some(0) };
+        Self {
+            inputs: inputs.iter().map(|input| input.to_string()).collect(),
+            selected: SMutex::create(selected),
+        }
+    }
+
+    pub fn inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
+    /// The selected input topic, `None` when no input is forwarded.
+    pub fn selected(&self) -> Option<String> {
+        let selected = *self.selected.lock_or_log("mux");
+        selected.map(|index| self.inputs[index].clone())
+    }
+
+    /// Selects an input by topic name, returns `false` if it is not an input of the mux.
+    pub fn select(&self, input: &str) -> bool {
+        match self.inputs.iter().position(|i| i == input) {
+            Some(index) => {
+                *self.selected.lock_or_log("mux") = Some(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Stops forwarding any input.
+    pub fn deselect(&self) {
+        *self.selected.lock_or_log("mux") = None;
+    }
+
+    pub(crate) fn is_selected(&self, index: usize) -> bool {
+        *self.selected.lock_or_log("mux") == Some(index)
+    }
+}
+
+/// Publisher created through the r2r node, so that the tools work with any node type.
+pub(crate) fn publisher<M>(
+    r2r_node: &SMutex<r2r::Node>,
+    logger: String,
+    topic: &str,
+    qos_profile: r2r::QosProfile,
+) -> crate::Result<crate::future::Publisher<M>>
+where
+    M: r2r::WrappedTypesupport,
+{
+    let r2r_publisher = {
+        let mut r2r_node = r2r_node.lock_err("r2r_node")?;
+        let r2r_publisher = r2r_node.create_publisher(topic, qos_profile)?;
+        SMutex::create(r2r_publisher)
+    };
+    Ok(crate::future::Publisher::Defined {
+        logger,
+        r2r_publisher,
+        throttle: None,
+        intra_process: None,
+    })
+}
@@ -0,0 +1,105 @@
+use super::Mux;
+use crate::api::NodeMulti;
+use crate::{SubscriptionOptions, ThrottlePolicy};
+
+/// Republishes the messages of `from` on `to`.
+pub fn relay<N, M>(
+    node: &N,
+    from: &str,
+    to: &str,
+    qos_profile: r2r::QosProfile,
+) -> crate::Result<()>
+where
+    N: NodeMulti,
+    M: Send + 'static + r2r::WrappedTypesupport,
+{
+    transform(node, from, to, qos_profile, |msg: M| msg)
+}
+
+/// Publishes on `to` the result of `f` for each message of `from`.
+pub fn transform<N, A, B, F>(
+    node: &N,
+    from: &str,
+    to: &str,
+    qos_profile: r2r::QosProfile,
+    f: F,
+) -> crate::Result<()>
+where
+    N: NodeMulti,
+    A: Send + 'static + r2r::WrappedTypesupport,
+    B: Send + 'static + r2r::WrappedTypesupport,
+    F: Fn(A) -> B + Send + Sync + 'static,
+{
+    let publisher = super::publisher::<B>(&node.r2r(), node.logger(), to, qos_profile.clone())?;
+    node.create_subscription_0(from, qos_profile, move |msg: A| {
+        publisher.publish(&f(msg));
+    })
+}
+
+/// Republishes the messages of `from` on `to` at most `max_hz` times per second.
+pub fn throttle<N, M>(
+    node: &N,
+    from: &str,
+    to: &str,
+    qos_profile: r2r::QosProfile,
+    max_hz: f64,
+    policy: ThrottlePolicy,
+) -> crate::Result<()>
+where
+    N: NodeMulti,
+    M: Send + 'static + r2r::WrappedTypesupport,
+{
+    let publisher = super::publisher::<M>(&node.r2r(), node.logger(), to, qos_profile.clone())?;
+    node.create_subscription_with_options_0(
+        from,
+        qos_profile,
+        SubscriptionOptions::new().throttle(max_hz, policy),
+        move |msg: M| {
+            publisher.publish(&msg);
+        },
+    )
+}
+
+/// Republishes on `output` the messages of the selected input, see `mux_select_service` to select it remotely.
+pub fn mux<N, M>(
+    node: &N,
+    inputs: &[&str],
+    output: &str,
+    qos_profile: r2r::QosProfile,
+) -> crate::Result<Mux>
+where
+    N: NodeMulti,
+    M: Send + 'static + r2r::WrappedTypesupport,
+{
+    let mux = Mux::new(inputs);
+    let publisher = super::publisher::<M>(&node.r2r(), node.logger(), output, qos_profile.clone())?;
+    for (index, input) in inputs.iter().enumerate() {
+        let mux = mux.clone();
+        let publisher = publisher.clone();
+        node.create_subscription_0(input, qos_profile.clone(), move |msg: M| {
+            if mux.is_selected(index) {
+                publisher.publish(&msg);
+            }
+        })?;
+    }
+    Ok(mux)
+}
+
+/// Service selecting the input of `mux`, the request and response types are up to `handler`.
+pub fn mux_select_service<N, S, H>(
+    node: &N,
+    mux: &Mux,
+    service_name: &str,
+    qos_profile: r2r::QosProfile,
+    handler: H,
+) -> crate::Result<()>
+where
+    N: NodeMulti,
+    S: 'static + Send + Sync + r2r::WrappedServiceTypeSupport,
+    H: Fn(&Mux, S::Request) -> S::Response + Send + Sync + 'static,
+{
+    let mux = mux.clone();
+    node.create_service_0::<S, _>(service_name, qos_profile, move |request| {
+        handler(&mux, request)
+    })
+}
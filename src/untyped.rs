@@ -0,0 +1,55 @@
+use futures::StreamExt;
+use futures::stream::BoxStream;
+
+use crate::{MutexLockErr, SMutex};
+
+/// Serialized (CDR) message, as received from the middleware.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawMessage {
+    pub type_name: String,
+    pub data: Vec<u8>,
+}
+
+/// Subscribes to `topic` of type `topic_type` (e.g. "std_msgs/msg/String"), messages that can not be converted are logged and skipped.
+pub(crate) fn subscribe_untyped(
+    r2r_node: &SMutex<r2r::Node>,
+    topic: &str,
+    topic_type: &str,
+    qos_profile: r2r::QosProfile,
+    logger: String,
+) -> crate::Result<BoxStream<'static, serde_json::Value>> {
+    let subscription = {
+        let mut node = r2r_node.lock_err("r2r_node")?;
+        node.subscribe_untyped(topic, topic_type, qos_profile)?
+    };
+    let subscription = subscription.filter_map(move |msg| {
+        let msg = match msg {
+            Ok(msg) => Some(msg),
+            Err(e) => {
+                r2r::log_error!(&logger, "untyped subscription error: {}", e);
+                None
+            }
+        };
+        futures::future::ready(msg)
+    });
+    Ok(subscription.boxed())
+}
+
+/// Subscribes to `topic` of type `topic_type` without deserializing the messages.
+pub(crate) fn subscribe_raw(
+    r2r_node: &SMutex<r2r::Node>,
+    topic: &str,
+    topic_type: &str,
+    qos_profile: r2r::QosProfile,
+) -> crate::Result<BoxStream<'static, RawMessage>> {
+    let subscription = {
+        let mut node = r2r_node.lock_err("r2r_node")?;
+        node.subscribe_raw(topic, topic_type, qos_profile)?
+    };
+    let type_name = topic_type.to_string();
+    let subscription = subscription.map(move |data| RawMessage {
+        type_name: type_name.clone(),
+        data,
+    });
+    Ok(subscription.boxed())
+}